@@ -0,0 +1,147 @@
+#![warn(missing_docs)]
+
+//! Derive macros for the `hlist` crate.
+//!
+//! These are re-exported by `hlist` when its `derive` feature is enabled, and should normally be used from there.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use syn::{Data, DeriveInput, Error, Fields, Type};
+
+/// Derives `hlist::Generic` for a struct or tuple struct.
+///
+/// The representation is an `HList` of the struct's fields, in declaration order.
+#[proc_macro_derive(Generic)]
+pub fn derive_generic(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match generic_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derives `hlist::LabelledGeneric` for a struct with named fields.
+///
+/// The representation is a record of the struct's fields, in declaration order,
+/// with each field labelled with its name.
+#[proc_macro_derive(LabelledGeneric)]
+pub fn derive_labelled_generic(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match labelled_generic_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// The fields of a struct, along with how to destructure and rebuild it.
+struct StructFields<'a> {
+    /// A pattern or expression with the struct's shape, using `bindings` for the fields.
+    shape: TokenStream2,
+    /// A local variable name for each field.
+    bindings: Vec<Ident>,
+    /// The type of each field.
+    types: Vec<&'a Type>,
+}
+
+fn struct_fields(input: &DeriveInput) -> Result<StructFields<'_>, Error> {
+    let data = match input.data {
+        Data::Struct(ref data) => data,
+        _ => return Err(Error::new(Span::call_site(), "only structs can be converted to and from an HList")),
+    };
+    let name = &input.ident;
+    let types = data.fields.iter().map(|field| &field.ty).collect();
+    let (shape, bindings) = match data.fields {
+        Fields::Named(ref fields) => {
+            let bindings: Vec<Ident> = fields.named.iter().map(|field| field.ident.clone().unwrap()).collect();
+            (quote!(#name { #(#bindings),* }), bindings)
+        }
+        Fields::Unnamed(ref fields) => {
+            let bindings: Vec<Ident> = (0..fields.unnamed.len())
+                .map(|i| Ident::new(&format!("field_{}", i), Span::call_site()))
+                .collect();
+            (quote!(#name(#(#bindings),*)), bindings)
+        }
+        Fields::Unit => (quote!(#name), Vec::new()),
+    };
+    Ok(StructFields { shape, bindings, types })
+}
+
+/// Builds nested `Cons`es ending in `Nil` from a list of types, expressions, or patterns.
+fn cons_list<T: quote::ToTokens>(items: &[T], as_type: bool) -> TokenStream2 {
+    items.iter().rev().fold(quote!(::hlist::Nil), |tail, item| {
+        if as_type {
+            quote!(::hlist::Cons<#item, #tail>)
+        } else {
+            quote!(::hlist::Cons(#item, #tail))
+        }
+    })
+}
+
+fn generic_impl(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let StructFields { shape, bindings, types } = struct_fields(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let repr = cons_list(&types, true);
+    let list = cons_list(&bindings, false);
+    Ok(quote! {
+        impl #impl_generics ::hlist::Generic for #name #ty_generics #where_clause {
+            type Repr = #repr;
+
+            fn into_hlist(self) -> Self::Repr {
+                let #shape = self;
+                #list
+            }
+
+            fn from_hlist(repr: Self::Repr) -> Self {
+                let #list = repr;
+                #shape
+            }
+        }
+    })
+}
+
+fn labelled_generic_impl(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    if let Data::Struct(ref data) = input.data {
+        if let Fields::Unnamed(ref fields) = data.fields {
+            return Err(Error::new_spanned(fields, "LabelledGeneric requires named fields"));
+        }
+    }
+    let StructFields { shape, bindings, types } = struct_fields(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field_types: Vec<TokenStream2> = bindings.iter().zip(&types)
+        .map(|(binding, ty)| quote!(::hlist::Field<::hlist::Label!(#binding), #ty>))
+        .collect();
+    let fields: Vec<TokenStream2> = bindings.iter()
+        .map(|binding| quote!(::hlist::Field::new(stringify!(#binding), #binding)))
+        .collect();
+    let repr = cons_list(&field_types, true);
+    let list = cons_list(&fields, false);
+    let pattern = cons_list(&bindings, false);
+    let values = if bindings.is_empty() {
+        quote!(#name)
+    } else {
+        quote!(#name { #(#bindings: #bindings.value),* })
+    };
+    Ok(quote! {
+        impl #impl_generics ::hlist::LabelledGeneric for #name #ty_generics #where_clause {
+            type Repr = #repr;
+
+            fn into_labelled(self) -> Self::Repr {
+                let #shape = self;
+                #list
+            }
+
+            fn from_labelled(repr: Self::Repr) -> Self {
+                let #pattern = repr;
+                #values
+            }
+        }
+    })
+}
@@ -0,0 +1,254 @@
+//! A dependency-injection container: an `HList` of values and an `HList` of providers that build values from other values.
+//! Dependencies are looked up by type, as with `Find`, so a missing dependency is a compile error.
+
+use std::marker::PhantomData;
+
+use super::{Cons, Find, Here, Nil, There};
+
+/// Holds registered values and providers, and builds values of any type that can be resolved from them.
+///
+/// A type is resolved either by cloning a value of that type added with `with()`,
+/// or by calling the provider for that type added with `provide()`, with each of its arguments resolved in turn.
+/// Providers are called every time their type is resolved.
+///
+/// Each type should be registered once: if a type is both a value and provided, or provided twice,
+/// type inference cannot choose between them.
+///
+/// ```rust
+/// use hlist::Container;
+///
+/// #[derive(Clone)]
+/// struct Config { url: &'static str }
+/// struct Database { url: &'static str }
+/// struct UserService { db: Database, config: Config }
+///
+/// let container = Container::new()
+///     .with(Config { url: "postgres://localhost" })
+///     .provide(|config: Config| Database { url: config.url })
+///     .provide(|db: Database, config: Config| UserService { db, config });
+///
+/// let service: UserService = container.resolve();
+/// assert!(service.db.url == "postgres://localhost");
+/// assert!(service.config.url == "postgres://localhost");
+/// ```
+///
+/// ```rust,compile_fail
+/// use hlist::Container;
+///
+/// struct Config;
+/// struct Database;
+///
+/// let container = Container::new().provide(|_: Config| Database);
+/// // Config was never registered.
+/// let db: Database = container.resolve();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Container<Values, Providers> {
+    values: Values,
+    providers: Providers,
+}
+
+impl Container<Nil, Nil> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Container {
+            values: Nil,
+            providers: Nil,
+        }
+    }
+}
+
+impl Default for Container<Nil, Nil> {
+    fn default() -> Self {
+        Container::new()
+    }
+}
+
+impl<Values, Providers> Container<Values, Providers> {
+    /// Consumes the container, returning a new container that also holds `value`.
+    pub fn with<T>(self, value: T) -> Container<Cons<T, Values>, Providers> {
+        Container {
+            values: Cons(value, self.values),
+            providers: self.providers,
+        }
+    }
+
+    /// Consumes the container, returning a new container that can also build values with `f`.
+    ///
+    /// `f` may take up to 12 arguments, each of a type that the container can resolve.
+    /// Closures need their argument types annotated.
+    #[allow(clippy::type_complexity)]
+    pub fn provide<Args, F>(self, f: F) -> Container<Values, Cons<Provider<<F as Construct<Args>>::Output, Args, F>, Providers>>
+        where F: Construct<Args> {
+        Container {
+            values: self.values,
+            providers: Cons(Provider { f, types: PhantomData }, self.providers),
+        }
+    }
+
+    /// Builds a `T`, from a value or a provider.
+    ///
+    /// As with `Find`, users should normally allow type inference to create `I`.
+    pub fn resolve<T, I>(&self) -> T
+        where Self: Resolve<T, I> {
+        Resolve::resolve(self)
+    }
+
+    /// Consumes the container, returning the `HList` of values added with `with()`, most recent first.
+    pub fn into_values(self) -> Values {
+        self.values
+    }
+}
+
+/// A function registered with `Container::provide()` that builds a `T` from the `HList` of arguments `Args`.
+#[derive(Clone, Copy, Debug)]
+pub struct Provider<T, Args, F> {
+    f: F,
+    types: PhantomData<fn(Args) -> T>,
+}
+
+/// A function that can be called with an `HList` of arguments `Args`.
+///
+/// Implemented for functions and closures taking up to 12 arguments.
+pub trait Construct<Args> {
+    /// The return type of the function.
+    type Output;
+
+    /// Calls the function with the elements of `args` as its arguments.
+    fn construct(&self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_construct {
+    ($($arg:ident $binding:ident),*) => {
+        impl<F, R, $($arg),*> Construct<HList![$($arg),*]> for F
+            where F: Fn($($arg),*) -> R {
+            type Output = R;
+
+            fn construct(&self, args: HList![$($arg),*]) -> R {
+                let hlist_pat![$($binding),*] = args;
+                self($($binding),*)
+            }
+        }
+    };
+}
+
+impl_construct!();
+impl_construct!(A1 a1);
+impl_construct!(A1 a1, A2 a2);
+impl_construct!(A1 a1, A2 a2, A3 a3);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4, A5 a5);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11);
+impl_construct!(A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11, A12 a12);
+
+/// `FindProvider<T, I>` is implemented for an `HList` of `Provider`s if index `I` of it is a provider of `T`.
+pub trait FindProvider<T, I> {
+    /// The `HList` of the provider's argument types.
+    type Args;
+
+    /// Calls the provider.
+    fn construct(&self, args: Self::Args) -> T;
+}
+
+impl<T, Args, F, Tail> FindProvider<T, Here> for Cons<Provider<T, Args, F>, Tail>
+    where F: Construct<Args, Output = T> {
+    type Args = Args;
+
+    fn construct(&self, args: Args) -> T {
+        self.0.f.construct(args)
+    }
+}
+
+impl<Head, T, Tail, TailIndex> FindProvider<T, There<TailIndex>> for Cons<Head, Tail>
+    where Tail: FindProvider<T, TailIndex> {
+    type Args = <Tail as FindProvider<T, TailIndex>>::Args;
+
+    fn construct(&self, args: Self::Args) -> T {
+        self.1.construct(args)
+    }
+}
+
+/// An index for `Resolve`, used when the value is cloned from index `I` of the container's values.
+#[allow(dead_code)]
+pub struct Existing<I>(PhantomData<I>);
+
+/// An index for `Resolve`, used when the value is built by the provider at index `I` of the container's providers.
+///
+/// `Args` holds the indices for resolving each of the provider's arguments.
+#[allow(dead_code)]
+pub struct Provided<I, Args>(PhantomData<(I, Args)>);
+
+/// `Resolve<T, I>` is implemented for a `Container` if it can build a `T`.
+///
+/// Usually used through `Container::resolve()`.
+pub trait Resolve<T, I> {
+    /// Builds a `T`.
+    fn resolve(&self) -> T;
+}
+
+impl<Values, Providers, T, I> Resolve<T, Existing<I>> for Container<Values, Providers>
+    where Values: Find<T, I>,
+          T: Clone {
+    fn resolve(&self) -> T {
+        self.values.get().clone()
+    }
+}
+
+impl<Values, Providers, T, I, ArgIndices> Resolve<T, Provided<I, ArgIndices>> for Container<Values, Providers>
+    where Providers: FindProvider<T, I>,
+          Self: ResolveMany<<Providers as FindProvider<T, I>>::Args, ArgIndices> {
+    fn resolve(&self) -> T {
+        self.providers.construct(self.resolve_many())
+    }
+}
+
+/// `ResolveMany<Targets, Indices>` is implemented for a `Container` if it can build every type in the `HList` `Targets`.
+pub trait ResolveMany<Targets, Indices> {
+    /// Builds an `HList` with a value of each type in `Targets`.
+    fn resolve_many(&self) -> Targets;
+}
+
+impl<C> ResolveMany<Nil, Nil> for C {
+    fn resolve_many(&self) -> Nil {
+        Nil
+    }
+}
+
+impl<C, THead, TTail, IHead, ITail> ResolveMany<Cons<THead, TTail>, Cons<IHead, ITail>> for C
+    where C: Resolve<THead, IHead> + ResolveMany<TTail, ITail> {
+    fn resolve_many(&self) -> Cons<THead, TTail> {
+        Cons(self.resolve(), self.resolve_many())
+    }
+}
+
+#[test]
+fn test_resolve_existing() {
+    let container = Container::new().with(5i32).with("Foo");
+    let a: i32 = container.resolve();
+    let b: &str = container.resolve();
+    assert!(a == 5);
+    assert!(b == "Foo");
+}
+
+#[test]
+fn test_resolve_provided() {
+    #[derive(Clone)]
+    struct Url(&'static str);
+    struct Pool(String);
+    struct Repo(Pool, u32);
+
+    let container = Container::new()
+        .provide(|pool: Pool, retries: u32| Repo(pool, retries))
+        .with(Url("db"))
+        .provide(|url: Url| Pool(url.0.to_uppercase()))
+        .provide(|| 3u32);
+
+    let repo: Repo = container.resolve();
+    assert!((repo.0).0 == "DB");
+    assert!(repo.1 == 3);
+}
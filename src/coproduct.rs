@@ -0,0 +1,409 @@
+//! Coproducts: values that are exactly one of several types, with the types present in the type of the coproduct.
+//! The counterpart of `Cons`/`Nil`, in the way that an enum is the counterpart of a struct.
+
+use super::{Cons, Func, Here, Nil, Poly, There};
+
+/// Builds a `Coproduct` type from its variant types, in order.
+///
+/// `Coproduct![A, B, C]` is equivalent to `Coproduct<A, Coproduct<B, Coproduct<C, CNil>>>`.
+#[macro_export]
+macro_rules! Coproduct {
+    () => { $crate::CNil };
+    ($head:ty $(, $tail:ty)* $(,)?) => {
+        $crate::Coproduct<$head, $crate::Coproduct!($($tail),*)>
+    };
+}
+
+/// A value that is either an `H` (`Inl`), or one of the types of the coproduct `T` (`Inr`).
+///
+/// `Coproduct<i32, Coproduct<&str, CNil>>` holds either an `i32` or a `&str`.
+/// Like `Find`, the position of a type is given by a `Here`/`There` index, which the compiler can usually infer
+/// if the type is present exactly once.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Coproduct;
+///
+/// # fn main() {
+/// type IntOrStr = Coproduct![i32, &'static str];
+///
+/// let a = IntOrStr::inject(5i32);
+/// assert!(a.get::<i32, _>() == Some(&5));
+/// assert!(a.get::<&str, _>() == None);
+///
+/// let b = IntOrStr::inject("Foo");
+/// let len = b.fold(hlist![|x: i32| x as usize, |s: &str| s.len()]);
+/// assert!(len == 3);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum Coproduct<H, T> {
+    /// The value is an `H`.
+    Inl(H),
+    /// The value is one of the types in `T`.
+    Inr(T),
+}
+
+/// The empty coproduct, which has no values.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum CNil {}
+
+impl<H, T> Coproduct<H, T> {
+    /// Builds the coproduct from a value of one of its types.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::Coproduct;
+    ///
+    /// # fn main() {
+    /// let a: Coproduct![i32, bool] = Coproduct::inject(true);
+    /// assert!(a.take::<bool, _>() == Some(true));
+    /// # }
+    /// ```
+    pub fn inject<X, I>(value: X) -> Self
+        where Self: Inject<X, I> {
+        <Self as Inject<X, I>>::inject(value)
+    }
+
+    /// Retrieves a `&X` if that is the type the coproduct holds.
+    pub fn get<X, I>(&self) -> Option<&X>
+        where Self: Uninject<X, I> {
+        Uninject::get(self)
+    }
+
+    /// Retrieves a `&mut X` if that is the type the coproduct holds.
+    pub fn get_mut<X, I>(&mut self) -> Option<&mut X>
+        where Self: Uninject<X, I> {
+        Uninject::get_mut(self)
+    }
+
+    /// Consumes the coproduct, returning the `X` if that is the type it holds.
+    pub fn take<X, I>(self) -> Option<X>
+        where Self: Uninject<X, I> {
+        Uninject::uninject(self).ok()
+    }
+
+    /// Consumes the coproduct, returning the `X` if that is the type it holds,
+    /// and otherwise the same value as a coproduct of the remaining types.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::Coproduct;
+    ///
+    /// # fn main() {
+    /// let a: Coproduct![i32, bool, char] = Coproduct::inject('c');
+    /// let rest: Coproduct![i32, char] = a.uninject::<bool, _>().unwrap_err();
+    /// assert!(rest.get::<char, _>() == Some(&'c'));
+    /// # }
+    /// ```
+    pub fn uninject<X, I>(self) -> Result<X, <Self as Uninject<X, I>>::Remainder>
+        where Self: Uninject<X, I> {
+        Uninject::uninject(self)
+    }
+
+    /// Consumes the coproduct, converting it into a coproduct `Out` with at least the same types, in any order.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::Coproduct;
+    ///
+    /// # fn main() {
+    /// let a: Coproduct![i32, bool] = Coproduct::inject(true);
+    /// let b: Coproduct![char, bool, i32] = a.embed();
+    /// assert!(b.get::<bool, _>() == Some(&true));
+    /// # }
+    /// ```
+    pub fn embed<Out, Indices>(self) -> Out
+        where Self: Embed<Out, Indices> {
+        Embed::embed(self)
+    }
+
+    /// Consumes the coproduct, converting it into the coproduct `Targets` if it holds one of the types of `Targets`,
+    /// and otherwise into a coproduct of the remaining types.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::Coproduct;
+    ///
+    /// # fn main() {
+    /// let a: Coproduct![i32, bool, char] = Coproduct::inject(true);
+    /// let b: Coproduct![char, bool] = a.subset().unwrap();
+    /// assert!(b.get::<bool, _>() == Some(&true));
+    ///
+    /// let c: Coproduct![i32, bool, char] = Coproduct::inject(5i32);
+    /// let rest: Coproduct![i32] = c.subset::<Coproduct![char, bool], _>().unwrap_err();
+    /// assert!(rest.get::<i32, _>() == Some(&5));
+    /// # }
+    /// ```
+    pub fn subset<Targets, Indices>(self) -> Result<Targets, <Self as Subset<Targets, Indices>>::Remainder>
+        where Self: Subset<Targets, Indices> {
+        Subset::subset(self)
+    }
+
+    /// Consumes the coproduct, applying whichever function matches the type it holds.
+    ///
+    /// `F` is either a `Poly` wrapping a `Func` implemented for each type,
+    /// or an `HList` of functions, one for each type in order.
+    /// Every function must return the same type.
+    pub fn fold<F, R>(self, f: F) -> R
+        where Self: Fold<F, R> {
+        Fold::fold(self, f)
+    }
+}
+
+/// `Inject<T, I>` is implemented for a coproduct if index `I` of the coproduct is a `T`.
+///
+/// Usually used through `Coproduct::inject()`.
+pub trait Inject<T, I> {
+    /// Builds the coproduct from a `T`.
+    fn inject(value: T) -> Self;
+}
+
+impl<T, Tail> Inject<T, Here> for Coproduct<T, Tail> {
+    fn inject(value: T) -> Self {
+        Coproduct::Inl(value)
+    }
+}
+
+impl<Head, T, Tail, TailIndex> Inject<T, There<TailIndex>> for Coproduct<Head, Tail>
+    where Tail: Inject<T, TailIndex> {
+    fn inject(value: T) -> Self {
+        Coproduct::Inr(Tail::inject(value))
+    }
+}
+
+/// `Uninject<T, I>` is implemented for a coproduct if index `I` of the coproduct is a `T`.
+///
+/// The counterpart of `Pluck`: if the coproduct holds a `T`, it is returned,
+/// and otherwise the value is returned as a coproduct of the remaining types.
+/// Usually used through the methods of `Coproduct`.
+pub trait Uninject<T, I>: Sized {
+    /// The coproduct of the remaining types, after removing the `T` at index `I`.
+    type Remainder;
+
+    /// Retrieves a `&T` if the coproduct holds one.
+    fn get(&self) -> Option<&T>;
+
+    /// Retrieves a `&mut T` if the coproduct holds one.
+    fn get_mut(&mut self) -> Option<&mut T>;
+
+    /// Returns the `T` if the coproduct holds one, and otherwise the remainder.
+    fn uninject(self) -> Result<T, Self::Remainder>;
+}
+
+impl<T, Tail> Uninject<T, Here> for Coproduct<T, Tail> {
+    type Remainder = Tail;
+
+    fn get(&self) -> Option<&T> {
+        match *self {
+            Coproduct::Inl(ref value) => Some(value),
+            Coproduct::Inr(_) => None,
+        }
+    }
+    fn get_mut(&mut self) -> Option<&mut T> {
+        match *self {
+            Coproduct::Inl(ref mut value) => Some(value),
+            Coproduct::Inr(_) => None,
+        }
+    }
+    fn uninject(self) -> Result<T, Tail> {
+        match self {
+            Coproduct::Inl(value) => Ok(value),
+            Coproduct::Inr(rest) => Err(rest),
+        }
+    }
+}
+
+impl<Head, T, Tail, TailIndex> Uninject<T, There<TailIndex>> for Coproduct<Head, Tail>
+    where Tail: Uninject<T, TailIndex> {
+    type Remainder = Coproduct<Head, <Tail as Uninject<T, TailIndex>>::Remainder>;
+
+    fn get(&self) -> Option<&T> {
+        match *self {
+            Coproduct::Inl(_) => None,
+            Coproduct::Inr(ref rest) => rest.get(),
+        }
+    }
+    fn get_mut(&mut self) -> Option<&mut T> {
+        match *self {
+            Coproduct::Inl(_) => None,
+            Coproduct::Inr(ref mut rest) => rest.get_mut(),
+        }
+    }
+    fn uninject(self) -> Result<T, Self::Remainder> {
+        match self {
+            Coproduct::Inl(head) => Err(Coproduct::Inl(head)),
+            Coproduct::Inr(rest) => rest.uninject().map_err(Coproduct::Inr),
+        }
+    }
+}
+
+/// `Embed<Out, Indices>` is implemented for a coproduct if every one of its types is present in the coproduct `Out`.
+///
+/// `Indices` is an `HList` of the index of each type in `Out`.
+/// As with `Find`, users should normally allow type inference to create `Indices`.
+/// Usually used through `Coproduct::embed()`.
+pub trait Embed<Out, Indices> {
+    /// Consumes the coproduct, converting it into an `Out` holding the same value.
+    fn embed(self) -> Out;
+}
+
+impl<Out> Embed<Out, Nil> for CNil {
+    fn embed(self) -> Out {
+        match self {}
+    }
+}
+
+impl<H, T, Out, HeadIndex, TailIndices> Embed<Out, Cons<HeadIndex, TailIndices>> for Coproduct<H, T>
+    where Out: Inject<H, HeadIndex>,
+          T: Embed<Out, TailIndices> {
+    fn embed(self) -> Out {
+        match self {
+            Coproduct::Inl(head) => Out::inject(head),
+            Coproduct::Inr(rest) => rest.embed(),
+        }
+    }
+}
+
+/// `Subset<Targets, Indices>` is implemented for a coproduct if every type of the coproduct `Targets` is present in it.
+///
+/// The counterpart of `Sculpt`: the value is converted into a `Targets` if it holds one of those types,
+/// and otherwise into a coproduct of the remaining types.
+/// `Indices` is an `HList` of the index of each type of `Targets`.
+/// As with `Find`, users should normally allow type inference to create `Indices`.
+/// Usually used through `Coproduct::subset()`.
+pub trait Subset<Targets, Indices>: Sized {
+    /// The coproduct of the remaining types, after removing the types of `Targets`.
+    type Remainder;
+
+    /// Returns the value as a `Targets` if it holds one of those types, and otherwise the remainder.
+    fn subset(self) -> Result<Targets, Self::Remainder>;
+}
+
+impl<Source> Subset<CNil, Nil> for Source {
+    type Remainder = Source;
+
+    fn subset(self) -> Result<CNil, Source> {
+        Err(self)
+    }
+}
+
+impl<Source, H, T, HeadIndex, TailIndices> Subset<Coproduct<H, T>, Cons<HeadIndex, TailIndices>> for Source
+    where Source: Uninject<H, HeadIndex>,
+          <Source as Uninject<H, HeadIndex>>::Remainder: Subset<T, TailIndices> {
+    type Remainder = <<Source as Uninject<H, HeadIndex>>::Remainder as Subset<T, TailIndices>>::Remainder;
+
+    fn subset(self) -> Result<Coproduct<H, T>, Self::Remainder> {
+        match self.uninject() {
+            Ok(head) => Ok(Coproduct::Inl(head)),
+            Err(rest) => rest.subset().map(Coproduct::Inr),
+        }
+    }
+}
+
+/// Consumes a coproduct, applying whichever function matches the type it holds, and producing an `R`.
+///
+/// `F` is either a `Poly` wrapping a `Func` with `Output = R` implemented for each type,
+/// or an `HList` of functions returning `R`, one for each type in order.
+/// Usually used through `Coproduct::fold()`.
+pub trait Fold<F, R> {
+    /// Consumes the coproduct, applying the matching function.
+    fn fold(self, f: F) -> R;
+}
+
+impl<F, R> Fold<Poly<F>, R> for CNil {
+    fn fold(self, _: Poly<F>) -> R {
+        match self {}
+    }
+}
+
+impl<R> Fold<Nil, R> for CNil {
+    fn fold(self, _: Nil) -> R {
+        match self {}
+    }
+}
+
+impl<F, R, H, T> Fold<Poly<F>, R> for Coproduct<H, T>
+    where F: Func<H, Output = R>,
+          T: Fold<Poly<F>, R> {
+    fn fold(self, mut f: Poly<F>) -> R {
+        match self {
+            Coproduct::Inl(head) => f.0.call(head),
+            Coproduct::Inr(rest) => rest.fold(f),
+        }
+    }
+}
+
+impl<F, FTail, R, H, T> Fold<Cons<F, FTail>, R> for Coproduct<H, T>
+    where F: FnOnce(H) -> R,
+          T: Fold<FTail, R> {
+    fn fold(self, f: Cons<F, FTail>) -> R {
+        match self {
+            Coproduct::Inl(head) => (f.0)(head),
+            Coproduct::Inr(rest) => rest.fold(f.1),
+        }
+    }
+}
+
+#[test]
+fn test_inject_uninject() {
+    type Value = Coproduct![i32, bool, char];
+
+    let mut a = Value::inject(true);
+    assert!(a.get::<i32, _>().is_none());
+    *a.get_mut::<bool, _>().unwrap() = false;
+    assert!(a.get::<bool, _>() == Some(&false));
+
+    assert!(a.uninject::<bool, _>().ok() == Some(false));
+
+    let b = Value::inject(5i32);
+    let rest: Coproduct![i32, char] = b.uninject::<bool, _>().unwrap_err();
+    assert!(rest.get::<i32, _>() == Some(&5));
+
+    let c = Value::inject('c');
+    assert!(c.take::<char, _>() == Some('c'));
+}
+
+#[test]
+fn test_embed_subset() {
+    struct ParseError;
+    struct IoError;
+    struct DbError;
+
+    let parse: Coproduct![ParseError, IoError] = Coproduct::inject(IoError);
+    let app: Coproduct![DbError, IoError, ParseError] = parse.embed();
+    assert!(app.get::<IoError, _>().is_some());
+
+    let narrowed: Result<Coproduct![ParseError, IoError], Coproduct![DbError]> = app.subset();
+    assert!(narrowed.ok().and_then(|e| e.take::<IoError, _>()).is_some());
+
+    let db: Coproduct![DbError, IoError, ParseError] = Coproduct::inject(DbError);
+    let rest = db.subset::<Coproduct![IoError, ParseError], _>().err().unwrap();
+    assert!(rest.take::<DbError, _>().is_some());
+}
+
+#[test]
+fn test_fold() {
+    struct Describe;
+
+    impl Func<i32> for Describe {
+        type Output = String;
+        fn call(&mut self, arg: i32) -> String {
+            format!("int {}", arg)
+        }
+    }
+
+    impl Func<bool> for Describe {
+        type Output = String;
+        fn call(&mut self, arg: bool) -> String {
+            format!("bool {}", arg)
+        }
+    }
+
+    let a: Coproduct![i32, bool] = Coproduct::inject(5i32);
+    assert!(a.fold(Poly(Describe)) == "int 5");
+    let b: Coproduct![i32, bool] = Coproduct::inject(true);
+    assert!(!b.fold(hlist![|x: i32| x != 0, |b: bool| !b]));
+}
@@ -0,0 +1,393 @@
+//! Sets: `HList`s in which every type appears at most once, so that looking up an element by type is never ambiguous.
+
+use std::ops::{Add, Deref, DerefMut};
+
+use super::{Cons, Find, Nil, Sculpt};
+
+mod index {
+    use std::marker::PhantomData;
+
+    /// The index used by `Distinct` for each element: `I` is the index of the element in the list starting at that element.
+    ///
+    /// Not exported, so that users cannot name an index and skip the check.
+    #[allow(dead_code)]
+    pub struct Unique<I>(PhantomData<I>);
+}
+
+use self::index::Unique;
+
+/// `Distinct<Indices>` is implemented for an `HList` in which no type appears more than once.
+///
+/// The check works through type inference: if an element's type appears again later in the list,
+/// the compiler cannot choose its index, and reports that type annotations are needed.
+/// `Indices` is built from a type that is private to this crate, so it can only ever be inferred:
+/// there is no way to name an index that would skip the check.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Distinct;
+///
+/// fn distinct<I, L: Distinct<I>>(_: &L) {}
+///
+/// # fn main() {
+/// distinct(&hlist![5i32, "Foo", true]);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Distinct;
+///
+/// fn distinct<I, L: Distinct<I>>(_: &L) {}
+///
+/// # fn main() {
+/// distinct(&hlist![5i32, "Foo", 6i32]);
+/// # }
+/// ```
+pub trait Distinct<Indices> {}
+
+impl Distinct<Nil> for Nil {}
+
+impl<H, T, I, TailIndices> Distinct<Cons<Unique<I>, TailIndices>> for Cons<H, T>
+    where Cons<H, T>: Find<H, I>,
+          T: Distinct<TailIndices> {}
+
+/// An `HList` in which no type appears more than once.
+///
+/// Every type in an `HSet` can be found with `Find` without any ambiguity.
+/// Both `new()` and `push()` fail to compile if a type would appear twice, using `Distinct`.
+/// The `HSet` dereferences to the `HList` it holds.
+///
+/// Because the check works through type inference, the error for a duplicate type is not a direct one:
+///
+/// ```text
+/// error[E0283]: type annotations needed
+///   |
+///   |     let set = HSet::new(hlist![5i32, "Foo"]).push(6i32);
+///   |                                              ^^^^ ---- type must be known at this point
+///   |                                              |
+///   |                                              cannot infer type of the type parameter `I` declared on the method `push`
+///   |
+///   = note: multiple `impl`s satisfying `Cons<i32, Cons<i32, Cons<&str, Nil>>>: Find<i32, _>` found in the `hlist` crate:
+/// ```
+///
+/// The note names the duplicated type, here `i32`.
+/// The compiler also suggests specifying `I`, but `I` is built from a type private to this crate and cannot be named,
+/// so the only fix is to remove the duplicate.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Find, HSet};
+///
+/// # fn main() {
+/// let set = HSet::new(hlist![5i32, "Foo"]).push(true);
+/// let a: i32 = *set.get();
+/// assert!(a == 5);
+/// assert!(*Find::<bool, _>::get(&*set));
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::HSet;
+///
+/// # fn main() {
+/// let set = HSet::new(hlist![5i32, "Foo"]).push(6i32);
+/// # }
+/// ```
+///
+/// Only the empty set has a `Default`, so that duplicates cannot be introduced through it:
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::HSet;
+///
+/// # fn main() {
+/// let set = HSet::<HList![i32, i32]>::default();
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HSet<L>(L);
+
+impl HSet<Nil> {
+    /// Creates an empty set.
+    pub fn empty() -> Self {
+        HSet(Nil)
+    }
+}
+
+impl Default for HSet<Nil> {
+    fn default() -> Self {
+        HSet::empty()
+    }
+}
+
+impl<L> HSet<L> {
+    /// Creates a set from an `HList`, which must not contain any type more than once.
+    pub fn new<I>(list: L) -> Self
+        where L: Distinct<I> {
+        HSet(list)
+    }
+
+    /// Consumes the set, and returns a new set with `item` at the beginning.
+    ///
+    /// `N` must not already be in the set.
+    pub fn push<N, I>(self, item: N) -> HSet<Cons<N, L>>
+        where Cons<N, L>: Distinct<I> {
+        HSet(Cons(item, self.0))
+    }
+
+    /// Consumes the set, returning the `HList` it holds.
+    pub fn into_inner(self) -> L {
+        self.0
+    }
+}
+
+impl<L> Deref for HSet<L> {
+    type Target = L;
+
+    fn deref(&self) -> &L {
+        &self.0
+    }
+}
+
+impl<L> DerefMut for HSet<L> {
+    fn deref_mut(&mut self) -> &mut L {
+        &mut self.0
+    }
+}
+
+/// `ContainsAll<Targets, Indices>` is implemented for an `HList` if every type in the `HList` `Targets` can be found in it.
+///
+/// Like `FindMany`, but only checks the types, without borrowing anything.
+/// `Indices` is an `HList` of indices, one for each element of `Targets`.
+pub trait ContainsAll<Targets, Indices> {}
+
+impl<L> ContainsAll<Nil, Nil> for L {}
+
+impl<L, THead, TTail, IHead, ITail> ContainsAll<Cons<THead, TTail>, Cons<IHead, ITail>> for L
+    where L: Find<THead, IHead> + ContainsAll<TTail, ITail> {}
+
+/// `Union<Other, Out, Indices>` is implemented for an `HList` if `Out` is its union with the `HList` `Other`.
+///
+/// Neither list may contain a type more than once, which is checked as with `Distinct`.
+/// `Out` is every element of `self`, in order, followed by the elements of `Other` that `self` does not contain, in any order.
+///
+/// Stable Rust cannot tell whether two types are different, so the union cannot be computed from the two lists;
+/// instead the caller names `Out`, usually with a type annotation, and the compiler checks that it is exactly the union.
+/// As with `Find`, users should allow type inference to create `Indices`,
+/// which also holds the types taken from `Other`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Union;
+///
+/// struct Read;
+/// struct Write;
+/// struct Network;
+///
+/// # fn main() {
+/// let storage = hlist![Read, Write];
+/// let sync = hlist![Network, Read];
+/// let hlist_pat![Read, Write, Network] = storage.union(sync);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Union;
+///
+/// struct Read;
+/// struct Write;
+/// struct Network;
+///
+/// # fn main() {
+/// // Network is missing.
+/// let hlist_pat![Read, Write] = hlist![Read, Write].union(hlist![Network, Read]);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Union;
+///
+/// # fn main() {
+/// // `other` contains two `i32`s.
+/// let both: HList![i32, bool] = hlist![5i32, true].union(hlist![7i32, 6i32]);
+/// # }
+/// ```
+pub trait Union<Other, Out, Indices> {
+    /// Consumes both `HList`s, returning the elements of `self` followed by the elements of `other` that `self` does not contain.
+    fn union(self, other: Other) -> Out;
+}
+
+impl<L, Other, Out, Extra, ExtraIndices, SharedIndices, SelfDistinct, OtherDistinct, DistinctIndices>
+    Union<Other, Out, (Extra, ExtraIndices, SharedIndices, SelfDistinct, OtherDistinct, DistinctIndices)> for L
+    where L: Distinct<SelfDistinct> + Add<Extra, Output = Out>
+             + ContainsAll<<Other as Sculpt<Extra, ExtraIndices>>::Remainder, SharedIndices>,
+          Other: Distinct<OtherDistinct> + Sculpt<Extra, ExtraIndices>,
+          Out: Distinct<DistinctIndices> {
+    fn union(self, other: Other) -> Out {
+        self + other.sculpt().0
+    }
+}
+
+/// `Intersect<Other, Out, Indices>` is implemented for an `HList` if `Out` is its intersection with the `HList` `Other`.
+///
+/// Neither list may contain a type more than once, which is checked as with `Distinct`.
+/// `Out` is the elements of `self` that `Other` also contains, in any order.
+///
+/// As with `Union`, the caller names `Out` and the compiler checks that it is exactly the intersection.
+/// As with `Find`, users should allow type inference to create `Indices`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Intersect;
+///
+/// # fn main() {
+/// let both: HList![bool, i32] = hlist![5i32, "Foo", true].intersect(&hlist![false, 'c', 0i32]);
+/// let hlist_pat![a, b] = both;
+/// assert!(a);
+/// assert!(b == 5);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Intersect;
+///
+/// # fn main() {
+/// // The bool is missing.
+/// let both: HList![i32] = hlist![5i32, "Foo", true].intersect(&hlist![false, 'c', 0i32]);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Intersect;
+///
+/// # fn main() {
+/// // `self` contains two `i32`s.
+/// let both: HList![bool] = hlist![5i32, 6i32, true].intersect(&hlist![false]);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Intersect;
+///
+/// # fn main() {
+/// // `other` contains two `char`s.
+/// let both: HList![bool] = hlist![5i32, true].intersect(&hlist![false, 'c', 'd']);
+/// # }
+/// ```
+pub trait Intersect<Other, Out, Indices> {
+    /// Consumes the `HList`, keeping only the elements whose types `other` also contains.
+    fn intersect(self, other: &Other) -> Out;
+}
+
+impl<L, Other, Out, OutIndices, OtherIndices, SelfDistinct, OtherDistinct, DistinctIndices>
+    Intersect<Other, Out, (OutIndices, OtherIndices, SelfDistinct, OtherDistinct, DistinctIndices)> for L
+    where L: Distinct<SelfDistinct> + Sculpt<Out, OutIndices>,
+          Other: Distinct<OtherDistinct> + ContainsAll<Out, OtherIndices>,
+          <L as Sculpt<Out, OutIndices>>::Remainder: Add<Other>,
+          <<L as Sculpt<Out, OutIndices>>::Remainder as Add<Other>>::Output: Distinct<DistinctIndices> {
+    fn intersect(self, _: &Other) -> Out {
+        self.sculpt().0
+    }
+}
+
+/// `Difference<Other, Out, Indices>` is implemented for an `HList` if `Out` is what is left after removing the types in the `HList` `Other`.
+///
+/// Neither list may contain a type more than once, which is checked as with `Distinct`.
+/// `Out` is the elements of `self` that `Other` does not contain, in any order.
+///
+/// As with `Union`, the caller names `Out` and the compiler checks that it is exactly the difference.
+/// As with `Find`, users should allow type inference to create `Indices`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Difference;
+///
+/// # fn main() {
+/// let rest: HList![i32, &str] = hlist![5i32, "Foo", true].difference(&hlist![false, 'c']);
+/// let hlist_pat![a, b] = rest;
+/// assert!(a == 5);
+/// assert!(b == "Foo");
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Difference;
+///
+/// # fn main() {
+/// // The bool should have been removed.
+/// let rest: HList![i32, &str, bool] = hlist![5i32, "Foo", true].difference(&hlist![false, 'c']);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Difference;
+///
+/// # fn main() {
+/// // `self` contains two `i32`s.
+/// let rest: HList![bool] = hlist![1i32, 2i32, true].difference(&hlist![0i32]);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Difference;
+///
+/// # fn main() {
+/// // `other` contains two `char`s.
+/// let rest: HList![i32] = hlist![1i32, true].difference(&hlist![false, 'c', 'd']);
+/// # }
+/// ```
+pub trait Difference<Other, Out, Indices> {
+    /// Consumes the `HList`, removing the elements whose types `other` contains.
+    fn difference(self, other: &Other) -> Out;
+}
+
+impl<L, Other, Out, OutIndices, RemovedIndices, SelfDistinct, OtherDistinct, DistinctIndices>
+    Difference<Other, Out, (OutIndices, RemovedIndices, SelfDistinct, OtherDistinct, DistinctIndices)> for L
+    where L: Distinct<SelfDistinct> + Sculpt<Out, OutIndices>,
+          Other: Distinct<OtherDistinct> + ContainsAll<<L as Sculpt<Out, OutIndices>>::Remainder, RemovedIndices>,
+          Out: Add<Other>,
+          <Out as Add<Other>>::Output: Distinct<DistinctIndices> {
+    fn difference(self, _: &Other) -> Out {
+        self.sculpt().0
+    }
+}
+
+#[test]
+#[allow(clippy::explicit_auto_deref)]
+fn test_hset() {
+    let mut set = HSet::empty().push(5i32).push("Foo");
+    *set.get_mut() = 6i32;
+    let a: i32 = *set.get();
+    let b: &str = *set.get();
+    assert!(a == 6);
+    assert!(b == "Foo");
+
+    let list = HSet::new(Cons(true, Cons('c', Nil))).into_inner();
+    assert!(list.0);
+}
+
+#[test]
+fn test_set_operations() {
+    let union: HList![i32, &str, bool, char] = hlist![5i32, "Foo"].union(hlist![true, 6i32, 'c']);
+    assert!(union.0 == 5);
+
+    let empty: Nil = hlist![5i32].intersect(&hlist!["Foo"]);
+    let _ = empty;
+    let intersection: HList![&str] = hlist![5i32, "Foo"].intersect(&hlist![true, "Bar"]);
+    assert!(intersection.0 == "Foo");
+
+    let difference: HList![bool, i32] = hlist![5i32, 'c', true].difference(&hlist!['d']);
+    assert!(difference.1 .0 == 5);
+    let everything: HList![char] = hlist!['c'].difference(&Nil);
+    assert!(everything.0 == 'c');
+}
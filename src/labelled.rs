@@ -0,0 +1,313 @@
+//! Records: `HList`s of values tagged with type-level field names, so that fields can be looked up by name rather than by type.
+
+use std::marker::PhantomData;
+
+use super::{Cons, Here, Nil, There};
+
+/// A type-level field name, usually written with the `Label!` macro.
+///
+/// `HASH` is a hash of the name, so that the same name always produces the same type, in any crate.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Label<const HASH: u128>;
+
+/// Hashes a field name for use in a `Label`.
+///
+/// Used by the `Label!` macro; users should not normally need to call this.
+#[doc(hidden)]
+pub const fn label_hash(name: &str) -> u128 {
+    // 128-bit FNV-1a.
+    let bytes = name.as_bytes();
+    let mut hash: u128 = 0x6c62272e07bb014262b821756295c58d;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u128;
+        hash = hash.wrapping_mul(0x0000000001000000000000000000013B);
+        i += 1;
+    }
+    hash
+}
+
+/// Builds the `Label` type for a field name.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Field;
+///
+/// # fn main() {
+/// let field: Field<Label![first_name], &str> = Field::new("first_name", "Foo");
+/// assert!(field.value == "Foo");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! Label {
+    ($name:ident) => {
+        $crate::Label<{ $crate::label_hash(stringify!($name)) }>
+    };
+}
+
+/// Builds a record: an `HList` of `Field`s, in order.
+///
+/// `record!{ a: x, b: y }` is equivalent to `hlist![Field::<Label![a], _>::new("a", x), Field::<Label![b], _>::new("b", y)]`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::HList;
+///
+/// # fn main() {
+/// let person = record!{ first_name: "Foo", last_name: "Bar", age: 30 };
+/// assert!(*person.get_field::<Label![last_name], _>() == "Bar");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! record {
+    ($($name:ident : $value:expr),* $(,)?) => {
+        $crate::hlist![$($crate::Field::<$crate::Label![$name], _>::new(stringify!($name), $value)),*]
+    };
+}
+
+/// A value tagged with the field name `Name`, usually a `Label`.
+#[derive(Clone, Copy, Debug)]
+pub struct Field<Name, V> {
+    /// The field name, as a string.
+    pub name: &'static str,
+    /// The value of the field.
+    pub value: V,
+    name_type: PhantomData<Name>,
+}
+
+impl<Name, V> Field<Name, V> {
+    /// Tags `value` with the field name `Name`.
+    ///
+    /// `name` should be the same name that `Name` was built from.
+    pub fn new(name: &'static str, value: V) -> Self {
+        Field {
+            name,
+            value,
+            name_type: PhantomData,
+        }
+    }
+}
+
+/// `FindField<Name, I>` is implemented for a record if index `I` of the record is a `Field<Name, _>`.
+///
+/// Like `Find`, but searches by field name instead of by type, so that fields of the same type can be told apart.
+/// Usually used through `HList::get_field()`.
+pub trait FindField<Name, I> {
+    /// The type of the field's value.
+    type Value;
+
+    /// Retrieves a `&Value`.
+    fn find_field(&self) -> &Self::Value;
+
+    /// Retrieves a `&mut Value`.
+    fn find_field_mut(&mut self) -> &mut Self::Value;
+}
+
+impl<Name, V, Tail> FindField<Name, Here> for Cons<Field<Name, V>, Tail> {
+    type Value = V;
+
+    fn find_field(&self) -> &V {
+        &self.0.value
+    }
+    fn find_field_mut(&mut self) -> &mut V {
+        &mut self.0.value
+    }
+}
+
+impl<Head, Name, Tail, TailIndex> FindField<Name, There<TailIndex>> for Cons<Head, Tail>
+    where Tail: FindField<Name, TailIndex> {
+    type Value = <Tail as FindField<Name, TailIndex>>::Value;
+
+    fn find_field(&self) -> &Self::Value {
+        self.1.find_field()
+    }
+    fn find_field_mut(&mut self) -> &mut Self::Value {
+        self.1.find_field_mut()
+    }
+}
+
+/// `PluckField<Name, I>` is implemented for a record if index `I` of the record is a `Field<Name, _>`.
+///
+/// Like `Pluck`, but searches by field name instead of by type.
+pub trait PluckField<Name, I> {
+    /// The type of the field's value.
+    type Value;
+
+    /// The record left over after removing the field.
+    type Remainder;
+
+    /// Removes the field, returning it and the remainder of the record.
+    fn pluck_field(self) -> (Field<Name, Self::Value>, Self::Remainder);
+}
+
+impl<Name, V, Tail> PluckField<Name, Here> for Cons<Field<Name, V>, Tail> {
+    type Value = V;
+    type Remainder = Tail;
+
+    fn pluck_field(self) -> (Field<Name, V>, Tail) {
+        (self.0, self.1)
+    }
+}
+
+impl<Head, Name, Tail, TailIndex> PluckField<Name, There<TailIndex>> for Cons<Head, Tail>
+    where Tail: PluckField<Name, TailIndex> {
+    type Value = <Tail as PluckField<Name, TailIndex>>::Value;
+    type Remainder = Cons<Head, <Tail as PluckField<Name, TailIndex>>::Remainder>;
+
+    fn pluck_field(self) -> (Field<Name, Self::Value>, Self::Remainder) {
+        let (field, tail) = self.1.pluck_field();
+        (field, Cons(self.0, tail))
+    }
+}
+
+/// Converts a type to and from a record with the same fields, its labelled generic representation.
+///
+/// With the `derive` feature, `#[derive(LabelledGeneric)]` implements this for structs with named fields,
+/// using a record of the fields in declaration order.
+pub trait LabelledGeneric: Sized {
+    /// The record representation of the type.
+    type Repr;
+
+    /// Converts the value into its record representation.
+    fn into_labelled(self) -> Self::Repr;
+
+    /// Builds a value from its record representation.
+    fn from_labelled(repr: Self::Repr) -> Self;
+}
+
+/// An index for `Transmogrify`, used when the source and target are the same type.
+#[allow(dead_code)]
+pub enum Identity {}
+
+/// An index for `Transmogrify`, used when the source and target are different `LabelledGeneric` types.
+///
+/// `I` holds the indices for transmogrifying the source's record into the target's record.
+#[allow(dead_code)]
+pub struct Nested<I>(PhantomData<I>);
+
+/// Converts a value into a `Target` by matching up field names.
+///
+/// A `LabelledGeneric` struct can be transmogrified into another `LabelledGeneric` struct
+/// whenever every field of the target has a field of the same name in the source.
+/// Fields of the source that the target does not have are dropped, and fields may be in any order.
+/// Each field is either of the same type in both, or is itself transmogrified, so nested structs are handled too.
+///
+/// As with `Find`, users should normally allow type inference to create `Indices`.
+/// If a nested field has the same `LabelledGeneric` type in both structs,
+/// it could either be moved as-is or transmogrified into itself, and inference will fail to choose.
+/// Stable Rust has no way to prefer one over the other, so in that case the caller names the index,
+/// leaving the rest to inference.
+/// The index of a struct is `Nested` of an `HList` with one `(field index, value index)` pair for each field of the target, in order,
+/// and the value index of the shared field is `Identity`:
+///
+/// ```rust,ignore
+/// // `Account` has the fields `email: String` and `name: Name`, and `AccountDto` also has `name: Name`.
+/// let account = Transmogrify::<Account, Nested<HList![(_, _), (_, Identity)]>>::transmogrify(dto);
+/// ```
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+/// #[macro_use] extern crate hlist;
+/// use hlist::{LabelledGeneric, Transmogrify};
+///
+/// #[derive(LabelledGeneric)]
+/// struct AddressDto {
+///     street: String,
+///     zip: String,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct PersonDto {
+///     id: u64,
+///     name: String,
+///     address: AddressDto,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct Address {
+///     zip: String,
+///     street: String,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct Person {
+///     address: Address,
+///     name: String,
+/// }
+///
+/// # fn main() {
+/// let dto = PersonDto {
+///     id: 5,
+///     name: String::from("Foo"),
+///     address: AddressDto { street: String::from("Main St"), zip: String::from("12345") },
+/// };
+/// let person: Person = dto.transmogrify();
+/// assert!(person.name == "Foo");
+/// assert!(person.address.zip == "12345");
+/// # }
+/// ```
+pub trait Transmogrify<Target, Indices> {
+    /// Consumes the value, converting it into a `Target`.
+    fn transmogrify(self) -> Target;
+}
+
+impl<T> Transmogrify<T, Identity> for T {
+    fn transmogrify(self) -> T {
+        self
+    }
+}
+
+impl<Source, Target, I> Transmogrify<Target, Nested<I>> for Source
+    where Source: LabelledGeneric,
+          Target: LabelledGeneric,
+          <Source as LabelledGeneric>::Repr: TransmogrifyRecord<<Target as LabelledGeneric>::Repr, I> {
+    fn transmogrify(self) -> Target {
+        Target::from_labelled(self.into_labelled().transmogrify_record())
+    }
+}
+
+/// Converts a record into a `Target` record by matching up field names.
+///
+/// The record-level half of `Transmogrify`.
+/// `Indices` is an `HList` with one pair of indices for each field of `Target`:
+/// where the field is found in the source, and how its value is transmogrified.
+pub trait TransmogrifyRecord<Target, Indices> {
+    /// Consumes the record, converting it into a `Target`.
+    fn transmogrify_record(self) -> Target;
+}
+
+impl<Source> TransmogrifyRecord<Nil, Nil> for Source {
+    fn transmogrify_record(self) -> Nil {
+        Nil
+    }
+}
+
+impl<Source, Name, V, Tail, FieldIndex, ValueIndex, TailIndices>
+    TransmogrifyRecord<Cons<Field<Name, V>, Tail>, Cons<(FieldIndex, ValueIndex), TailIndices>> for Source
+    where Source: PluckField<Name, FieldIndex>,
+          <Source as PluckField<Name, FieldIndex>>::Value: Transmogrify<V, ValueIndex>,
+          <Source as PluckField<Name, FieldIndex>>::Remainder: TransmogrifyRecord<Tail, TailIndices> {
+    fn transmogrify_record(self) -> Cons<Field<Name, V>, Tail> {
+        let (field, rest) = self.pluck_field();
+        Cons(Field::new(field.name, field.value.transmogrify()), rest.transmogrify_record())
+    }
+}
+
+#[test]
+fn test_label_identity() {
+    fn same<T>(_: PhantomData<T>, _: PhantomData<T>) {}
+    same(PhantomData::<Label![first_name]>, PhantomData::<Label<{ label_hash("first_name") }>>);
+    assert!(label_hash("first_name") != label_hash("last_name"));
+}
+
+#[test]
+fn test_get_field() {
+    use super::HList;
+
+    let mut person = record!{ first_name: String::from("Foo"), last_name: String::from("Bar"), age: 30u32 };
+    person.get_field_mut::<Label![first_name], _>().push_str("Baz");
+    assert!(person.get_field::<Label![first_name], _>() == "FooBaz");
+    assert!(person.get_field::<Label![last_name], _>() == "Bar");
+    assert!(*person.get_field::<Label![age], _>() == 30u32);
+    assert!(person.0.name == "first_name");
+}
@@ -8,8 +8,84 @@
 #[macro_use]
 extern crate serde_derive;
 
+#[cfg(feature = "derive")]
+extern crate hlist_derive;
+
+#[cfg(feature = "derive")]
+pub use hlist_derive::{Generic, LabelledGeneric};
+
 use std::ops::Add;
 
+/// Builds an `HList` value from its elements, in order.
+///
+/// `hlist![a, b, c]` is equivalent to `Cons(a, Cons(b, Cons(c, Nil)))`, or `Nil.push(c).push(b).push(a)`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Cons, Nil, Find};
+///
+/// # fn main() {
+/// let list: Cons<i32, Cons<&str, Nil>> = hlist![5i32, "Foo"];
+/// let a: i32 = *list.get();
+/// assert!(a == 5);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! hlist {
+    () => { $crate::Nil };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        $crate::Cons($head, $crate::hlist!($($tail),*))
+    };
+}
+
+/// Builds an `HList` type from its element types, in order.
+///
+/// `HList![A, B, C]` is equivalent to `Cons<A, Cons<B, Cons<C, Nil>>>`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Cons, Nil};
+///
+/// # fn main() {
+/// let list: HList![i32, &str] = Cons(5i32, Cons("Foo", Nil));
+/// assert!(list.0 == 5);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! HList {
+    () => { $crate::Nil };
+    ($head:ty $(, $tail:ty)* $(,)?) => {
+        $crate::Cons<$head, $crate::HList!($($tail),*)>
+    };
+}
+
+/// Builds a pattern that destructures an `HList`, in order.
+///
+/// `hlist_pat![a, b, c]` is equivalent to `Cons(a, Cons(b, Cons(c, Nil)))`.
+/// Elements may be any pattern, including `_`.
+/// A final `..rest` binds the remainder of the list, and a bare `..` ignores it.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+///
+/// # fn main() {
+/// let hlist_pat![a, _, ..rest] = hlist![5i32, "Foo", true, 'c'];
+/// assert!(a == 5);
+/// let hlist_pat![b, ..] = rest;
+/// assert!(b);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! hlist_pat {
+    () => { $crate::Nil };
+    (.. $(,)?) => { _ };
+    (.. $rest:pat $(,)?) => { $rest };
+    ($head:pat) => { $crate::Cons($head, $crate::Nil) };
+    ($head:pat, $($tail:tt)*) => {
+        $crate::Cons($head, $crate::hlist_pat!($($tail)*))
+    };
+}
+
 /// The empty `HList`.
 #[derive(Clone, Copy, Debug, Default)]
 #[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
@@ -20,18 +96,234 @@ pub struct Nil;
 #[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
 pub struct Cons<H, T>(pub H, pub T);
 
-/// A marker trait that `Nil` and `Cons<H, T>` satisfies.
-/// Not currently used to enforce proper hlists, although this may change.
-/// Provides the `push()` method
+/// A trait that `Nil` and `Cons<H, T>` satisfies, provided `T` is itself an `HList`.
+/// Provides the `push()` method, and the length of the list.
+///
+/// The length is available both as a value and as a type, so that functions can require lists of the same length:
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::HList;
+///
+/// fn same_length<A: HList, B: HList<Len = A::Len>>(_: &A, _: &B) {}
+///
+/// # fn main() {
+/// let list = hlist![5i32, "Foo"];
+/// assert!(list.len() == 2);
+/// assert!(<HList![i32, &str, bool]>::LEN == 3);
+/// same_length(&list, &hlist![true, 'c']);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::HList;
+///
+/// fn same_length<A: HList, B: HList<Len = A::Len>>(_: &A, _: &B) {}
+///
+/// # fn main() {
+/// same_length(&hlist![5i32, "Foo"], &hlist![true]);
+/// # }
+/// ```
 pub trait HList: Sized {
+    /// The number of elements in the `HList`.
+    const LEN: usize;
+
+    /// The number of elements in the `HList`, as a type.
+    ///
+    /// `Here` is 0, and `There<N>` is 1 + `N`, as with indices.
+    type Len;
+
+    /// Returns the number of elements in the `HList`.
+    fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    /// Returns `true` if the `HList` is `Nil`.
+    fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+
     /// Consumes the `HList`, and returns a new HList with `item` at the beginning.
     fn push<N>(self, item: N) -> Cons<N, Self> {
         Cons(item, self)
     }
+
+    /// Borrows one element of each type in `Targets`, returning an `HList` of `&T`s in the order of `Targets`.
+    ///
+    /// The same type may be borrowed more than once.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// # fn main() {
+    /// let list = hlist![5i32, "Foo", true];
+    /// let hlist_pat![a, b] = list.get_many::<HList![bool, i32], _>();
+    /// assert!(*a);
+    /// assert!(*b == 5);
+    /// # }
+    /// ```
+    fn get_many<'a, Targets, Indices>(&'a self) -> <Self as FindMany<'a, Targets, Indices>>::Output
+        where Self: FindMany<'a, Targets, Indices> {
+        self.find_many()
+    }
+
+    /// Mutably borrows one element of each type in `Targets`, returning an `HList` of `&mut T`s in the order of `Targets`.
+    ///
+    /// The borrows are disjoint: this does not compile if `Targets` would need the same element twice.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// # fn main() {
+    /// let mut list = hlist![5i32, "Foo", true];
+    /// {
+    ///     let hlist_pat![a, b] = list.get_many_mut::<HList![bool, i32], _>();
+    ///     *a = false;
+    ///     *b += 1;
+    /// }
+    /// let hlist_pat![a, _, c] = list;
+    /// assert!(a == 6);
+    /// assert!(!c);
+    /// # }
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// # fn main() {
+    /// let mut list = hlist![5i32, true];
+    /// let hlist_pat![a, b] = list.get_many_mut::<HList![i32, i32], _>();
+    /// # }
+    /// ```
+    fn get_many_mut<'a, Targets, Indices>(&'a mut self) -> <Targets as ToMut<'a>>::Output
+        where Self: ToMut<'a>,
+              Targets: ToMut<'a>,
+              <Self as ToMut<'a>>::Output: Sculpt<<Targets as ToMut<'a>>::Output, Indices> {
+        self.to_mut().sculpt().0
+    }
+
+    /// Retrieves a reference to the element at position `N`, whatever its type.
+    ///
+    /// Useful when the `HList` contains more than one element of the same type.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// # fn main() {
+    /// let list = hlist![1u32, "Foo", 2u32];
+    /// assert!(*list.at::<0>() == 1);
+    /// assert!(*list.at::<2>() == 2);
+    /// # }
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// # fn main() {
+    /// let list = hlist![1u32, "Foo", 2u32];
+    /// list.at::<3>();
+    /// # }
+    /// ```
+    fn at<const N: usize>(&self) -> &<Self as At<<Nat<N> as ToIndex>::Index>>::Output
+        where Nat<N>: ToIndex,
+              Self: At<<Nat<N> as ToIndex>::Index> {
+        self.get_at()
+    }
+
+    /// Retrieves a mutable reference to the element at position `N`, whatever its type.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// # fn main() {
+    /// let mut list = hlist![1u32, "Foo", 2u32];
+    /// *list.at_mut::<2>() = 5;
+    /// assert!(*list.at::<2>() == 5);
+    /// # }
+    /// ```
+    fn at_mut<const N: usize>(&mut self) -> &mut <Self as At<<Nat<N> as ToIndex>::Index>>::Output
+        where Nat<N>: ToIndex,
+              Self: At<<Nat<N> as ToIndex>::Index> {
+        self.get_at_mut()
+    }
+
+    /// Retrieves a reference to the value of the field named `Name` in a record.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// # fn main() {
+    /// let person = record!{ first_name: "Foo", last_name: "Bar" };
+    /// assert!(*person.get_field::<Label![first_name], _>() == "Foo");
+    /// # }
+    /// ```
+    fn get_field<Name, I>(&self) -> &<Self as FindField<Name, I>>::Value
+        where Self: FindField<Name, I> {
+        self.find_field()
+    }
+
+    /// Retrieves a mutable reference to the value of the field named `Name` in a record.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// # fn main() {
+    /// let mut person = record!{ first_name: "Foo", last_name: "Bar" };
+    /// *person.get_field_mut::<Label![last_name], _>() = "Baz";
+    /// assert!(*person.get_field::<Label![last_name], _>() == "Baz");
+    /// # }
+    /// ```
+    fn get_field_mut<Name, I>(&mut self) -> &mut <Self as FindField<Name, I>>::Value
+        where Self: FindField<Name, I> {
+        self.find_field_mut()
+    }
+
+    /// Calls `f`, passing each argument by borrowing the element of that type from the `HList`.
+    ///
+    /// `f` may take up to 12 arguments, each a `&T` for a type `T` present in the `HList`, in any order.
+    /// Closures need their argument types annotated.
+    ///
+    /// ```rust
+    /// #[macro_use] extern crate hlist;
+    /// use hlist::HList;
+    ///
+    /// struct Database(&'static str);
+    /// struct UserId(u64);
+    ///
+    /// fn greet(id: &UserId, db: &Database) -> String {
+    ///     format!("user {} from {}", id.0, db.0)
+    /// }
+    ///
+    /// # fn main() {
+    /// let context = hlist![Database("main"), UserId(5), true];
+    /// assert!(context.call(greet) == "user 5 from main");
+    /// assert!(context.call(|verbose: &bool| *verbose));
+    /// # }
+    /// ```
+    fn call<F, Args, Indices>(&self, f: F) -> <F as Handler<Self, Args, Indices>>::Output
+        where F: Handler<Self, Args, Indices> {
+        f.handle(self)
+    }
+}
+
+impl HList for Nil {
+    const LEN: usize = 0;
+    type Len = Here;
 }
 
-impl HList for Nil {}
-impl<H, T> HList for Cons<H, T> {}
+impl<H, T: HList> HList for Cons<H, T> {
+    const LEN: usize = 1 + T::LEN;
+    type Len = There<T::Len>;
+}
 
 
 /// Used as an index into an `HList`.
@@ -111,6 +403,22 @@ pub trait Find<T, I> {
     /// let a: i32 = *list.get();
     /// assert!(a == 5);
     fn get_mut(&mut self) -> &mut T;
+
+    /// Consumes the `HList`, replacing the `T` with `new`.
+    /// Returns the old `T` and the updated `HList`.
+    ///
+    /// ```rust
+    /// use hlist::{HList, Nil, Find};
+    ///
+    /// let list = Nil.push(0i32).push(1i64);
+    /// let (old, list) = list.replace(5i32);
+    /// assert!(old == 0);
+    /// let a: i32 = *list.get();
+    /// assert!(a == 5);
+    fn replace(mut self, new: T) -> (T, Self) where Self: Sized {
+        let old = std::mem::replace(self.get_mut(), new);
+        (old, self)
+    }
 }
 
 impl<T, Tail> Find<T, Here> for Cons<T, Tail> {
@@ -132,6 +440,362 @@ impl<Head, T, Tail, TailIndex> Find<T, There<TailIndex>> for Cons<Head, Tail>
     }
 }
 
+/// `Pluck<T, I>` is implemented for an `HList` if index `I` of the `HList` is a `T`.
+///
+/// Like `Find`, but consumes the `HList`, moving the `T` out and returning it along with the rest of the list.
+///
+/// ```rust
+/// use hlist::{HList, Nil, Pluck};
+///
+/// let list = Nil.push("foo").push(5i32).push("bar");
+/// let (a, rest): (i32, _) = list.pluck();
+/// assert!(a == 5);
+/// // The type of rest is Cons<&str, Cons<&str, Nil>>
+/// assert!(rest.0 == "bar");
+/// assert!((rest.1).0 == "foo");
+/// ```
+pub trait Pluck<T, I> {
+    /// The `HList` left over after removing the `T` at index `I`.
+    type Remainder;
+
+    /// Removes the `T`, returning it and the remainder of the `HList`.
+    fn pluck(self) -> (T, Self::Remainder);
+}
+
+impl<T, Tail> Pluck<T, Here> for Cons<T, Tail> {
+    type Remainder = Tail;
+
+    fn pluck(self) -> (T, Tail) {
+        (self.0, self.1)
+    }
+}
+
+impl<Head, T, Tail, TailIndex> Pluck<T, There<TailIndex>> for Cons<Head, Tail>
+    where Tail: Pluck<T, TailIndex> {
+    type Remainder = Cons<Head, <Tail as Pluck<T, TailIndex>>::Remainder>;
+
+    fn pluck(self) -> (T, Self::Remainder) {
+        let (target, tail) = self.1.pluck();
+        (target, Cons(self.0, tail))
+    }
+}
+
+/// `ReplaceWith<T, U, I>` is implemented for an `HList` if index `I` of the `HList` is a `T`.
+///
+/// Replaces the `T` with a `U` computed from it, changing the type of the `HList` accordingly.
+///
+/// ```rust
+/// use hlist::{HList, Nil, Find, ReplaceWith};
+///
+/// let list = Nil.push("5").push(true);
+/// // The type of parsed is Cons<bool, Cons<i32, Nil>>
+/// let parsed = list.replace_with(|s: &str| s.parse::<i32>().unwrap());
+/// let a: i32 = *parsed.get();
+/// assert!(a == 5);
+/// ```
+pub trait ReplaceWith<T, U, I> {
+    /// The `HList` with the `T` at index `I` replaced by a `U`.
+    type Output;
+
+    /// Consumes the `HList`, replacing the `T` with `f(T)`.
+    fn replace_with<F: FnOnce(T) -> U>(self, f: F) -> Self::Output;
+}
+
+impl<T, U, Tail> ReplaceWith<T, U, Here> for Cons<T, Tail> {
+    type Output = Cons<U, Tail>;
+
+    fn replace_with<F: FnOnce(T) -> U>(self, f: F) -> Cons<U, Tail> {
+        Cons(f(self.0), self.1)
+    }
+}
+
+impl<Head, T, U, Tail, TailIndex> ReplaceWith<T, U, There<TailIndex>> for Cons<Head, Tail>
+    where Tail: ReplaceWith<T, U, TailIndex> {
+    type Output = Cons<Head, <Tail as ReplaceWith<T, U, TailIndex>>::Output>;
+
+    fn replace_with<F: FnOnce(T) -> U>(self, f: F) -> Self::Output {
+        Cons(self.0, self.1.replace_with(f))
+    }
+}
+
+/// `Sculpt<Target, Indices>` is implemented for an `HList` if every type in the `HList` `Target` can be plucked from it.
+///
+/// `Indices` is an `HList` of indices, one for each element of `Target`.
+/// As with `Find`, users should normally allow type inference to create it.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Sculpt;
+///
+/// # fn main() {
+/// let list = hlist![5i32, "Foo", true, 'c'];
+/// let (target, rest): (HList![bool, i32], _) = list.sculpt();
+/// let hlist_pat![a, b] = target;
+/// assert!(a);
+/// assert!(b == 5);
+/// let hlist_pat![c, d] = rest;
+/// assert!(c == "Foo");
+/// assert!(d == 'c');
+/// # }
+/// ```
+pub trait Sculpt<Target, Indices> {
+    /// The `HList` left over after removing every element of `Target`.
+    type Remainder;
+
+    /// Consumes the `HList`, rearranging it into `Target` and returning the remainder of the `HList`.
+    fn sculpt(self) -> (Target, Self::Remainder);
+}
+
+impl<Source> Sculpt<Nil, Nil> for Source {
+    type Remainder = Source;
+
+    fn sculpt(self) -> (Nil, Source) {
+        (Nil, self)
+    }
+}
+
+impl<THead, TTail, SHead, STail, IHead, ITail> Sculpt<Cons<THead, TTail>, Cons<IHead, ITail>> for Cons<SHead, STail>
+    where Cons<SHead, STail>: Pluck<THead, IHead>,
+          <Cons<SHead, STail> as Pluck<THead, IHead>>::Remainder: Sculpt<TTail, ITail> {
+    type Remainder = <<Cons<SHead, STail> as Pluck<THead, IHead>>::Remainder as Sculpt<TTail, ITail>>::Remainder;
+
+    fn sculpt(self) -> (Cons<THead, TTail>, Self::Remainder) {
+        let (head, rest) = self.pluck();
+        let (tail, remainder) = rest.sculpt();
+        (Cons(head, tail), remainder)
+    }
+}
+
+/// `FindMany<'a, Targets, Indices>` is implemented for an `HList` if every type in the `HList` `Targets` can be found in it.
+///
+/// `Indices` is an `HList` of indices, one for each element of `Targets`.
+/// Usually used through `HList::get_many()`.
+pub trait FindMany<'a, Targets, Indices> {
+    /// An `HList` of `&'a T` for each `T` in `Targets`.
+    type Output;
+
+    /// Borrows one element of each type in `Targets`.
+    fn find_many(&'a self) -> Self::Output;
+}
+
+impl<'a, L> FindMany<'a, Nil, Nil> for L {
+    type Output = Nil;
+
+    fn find_many(&'a self) -> Nil {
+        Nil
+    }
+}
+
+impl<'a, L, THead, TTail, IHead, ITail> FindMany<'a, Cons<THead, TTail>, Cons<IHead, ITail>> for L
+    where L: Find<THead, IHead> + FindMany<'a, TTail, ITail>,
+          THead: 'a {
+    type Output = Cons<&'a THead, <L as FindMany<'a, TTail, ITail>>::Output>;
+
+    fn find_many(&'a self) -> Self::Output {
+        Cons(self.get(), FindMany::<'a, TTail, ITail>::find_many(self))
+    }
+}
+
+/// A function that can be called with arguments borrowed from the `HList` `L`, each found by its type.
+///
+/// Implemented for functions and closures taking up to 12 arguments, each of them a `&T` for a `T` that can be found in `L`.
+/// `Args` is an `HList` of the argument types, and `Indices` an `HList` of where each is found in `L`.
+/// As with `Find`, users should normally allow type inference to create both.
+/// Usually used through `HList::call()`.
+pub trait Handler<L, Args, Indices> {
+    /// The return type of the function.
+    type Output;
+
+    /// Calls the function, borrowing each argument from `list`.
+    fn handle(self, list: &L) -> Self::Output;
+}
+
+macro_rules! impl_handler {
+    ($($arg:ident $index:ident),*) => {
+        impl<L, F, R, $($arg, $index),*> Handler<L, HList![$($arg),*], HList![$($index),*]> for F
+            where F: FnOnce($(&$arg),*) -> R,
+                  $(L: Find<$arg, $index>),* {
+            type Output = R;
+
+            #[allow(unused_variables)]
+            fn handle(self, list: &L) -> R {
+                self($(Find::<$arg, $index>::get(list)),*)
+            }
+        }
+    };
+}
+
+impl_handler!();
+impl_handler!(A1 I1);
+impl_handler!(A1 I1, A2 I2);
+impl_handler!(A1 I1, A2 I2, A3 I3);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4, A5 I5);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4, A5 I5, A6 I6);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4, A5 I5, A6 I6, A7 I7);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4, A5 I5, A6 I6, A7 I7, A8 I8);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4, A5 I5, A6 I6, A7 I7, A8 I8, A9 I9);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4, A5 I5, A6 I6, A7 I7, A8 I8, A9 I9, A10 I10);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4, A5 I5, A6 I6, A7 I7, A8 I8, A9 I9, A10 I10, A11 I11);
+impl_handler!(A1 I1, A2 I2, A3 I3, A4 I4, A5 I5, A6 I6, A7 I7, A8 I8, A9 I9, A10 I10, A11 I11, A12 I12);
+
+/// Converts a `&` borrow of an `HList` into an `HList` of `&` borrows of its elements.
+///
+/// The result is itself an `HList`, so `Find`, `Pluck`, and the rest can be used on it without moving or cloning the original elements.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Pluck, ToRef};
+///
+/// # fn main() {
+/// let list = hlist![String::from("Foo"), 5i32];
+/// let (a, _): (&String, _) = list.to_ref().pluck();
+/// assert!(a == "Foo");
+/// assert!(list.0 == "Foo");
+/// # }
+/// ```
+pub trait ToRef<'a> {
+    /// The `HList` of `&'a` borrows.
+    type Output;
+
+    /// Borrows every element of the `HList`.
+    fn to_ref(&'a self) -> Self::Output;
+}
+
+impl<'a> ToRef<'a> for Nil {
+    type Output = Nil;
+
+    fn to_ref(&'a self) -> Nil {
+        Nil
+    }
+}
+
+impl<'a, H: 'a, T: ToRef<'a>> ToRef<'a> for Cons<H, T> {
+    type Output = Cons<&'a H, <T as ToRef<'a>>::Output>;
+
+    fn to_ref(&'a self) -> Self::Output {
+        Cons(&self.0, self.1.to_ref())
+    }
+}
+
+/// Converts a `&mut` borrow of an `HList` into an `HList` of `&mut` borrows of its elements.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::ToMut;
+///
+/// # fn main() {
+/// let mut list = hlist![5i32, true];
+/// {
+///     let hlist_pat![a, b] = list.to_mut();
+///     *a += 1;
+///     *b = false;
+/// }
+/// assert!(list.0 == 6);
+/// assert!(!(list.1).0);
+/// # }
+/// ```
+pub trait ToMut<'a> {
+    /// The `HList` of `&'a mut` borrows.
+    type Output;
+
+    /// Mutably borrows every element of the `HList`.
+    fn to_mut(&'a mut self) -> Self::Output;
+}
+
+impl<'a> ToMut<'a> for Nil {
+    type Output = Nil;
+
+    fn to_mut(&'a mut self) -> Nil {
+        Nil
+    }
+}
+
+impl<'a, H: 'a, T: ToMut<'a>> ToMut<'a> for Cons<H, T> {
+    type Output = Cons<&'a mut H, <T as ToMut<'a>>::Output>;
+
+    fn to_mut(&'a mut self) -> Self::Output {
+        Cons(&mut self.0, self.1.to_mut())
+    }
+}
+
+/// `At<I>` is implemented for an `HList` if it has an element at index `I`, whatever its type.
+///
+/// Unlike `Find`, the index determines the type rather than the other way around.
+/// Usually used through `HList::at()`, which takes the index as a number.
+#[diagnostic::on_unimplemented(
+    message = "index out of bounds: the `HList` is too short",
+    label = "index out of bounds",
+)]
+pub trait At<I> {
+    /// The type of the element at index `I`.
+    type Output;
+
+    /// Retrieves a `&Output`.
+    fn get_at(&self) -> &Self::Output;
+
+    /// Retrieves a `&mut Output`.
+    fn get_at_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<H, T> At<Here> for Cons<H, T> {
+    type Output = H;
+
+    fn get_at(&self) -> &H {
+        &self.0
+    }
+    fn get_at_mut(&mut self) -> &mut H {
+        &mut self.0
+    }
+}
+
+impl<H, T, TailIndex> At<There<TailIndex>> for Cons<H, T>
+    where T: At<TailIndex> {
+    type Output = <T as At<TailIndex>>::Output;
+
+    fn get_at(&self) -> &Self::Output {
+        self.1.get_at()
+    }
+    fn get_at_mut(&mut self) -> &mut Self::Output {
+        self.1.get_at_mut()
+    }
+}
+
+/// A number, used to name an index with a constant rather than with `Here` and `There`.
+///
+/// `Nat<N>` implements `ToIndex` for `N` up to 63.
+#[allow(dead_code)]
+pub struct Nat<const N: usize>;
+
+/// Converts a `Nat<N>` into the equivalent index built from `Here` and `There`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is too large to be used as an index",
+    label = "indices above 63 are not supported",
+)]
+pub trait ToIndex {
+    /// The index, such that `Nat<0>` is `Here` and `Nat<N + 1>` is `There<Nat<N>::Index>`.
+    type Index;
+}
+
+impl ToIndex for Nat<0> {
+    type Index = Here;
+}
+
+macro_rules! impl_to_index {
+    ($prev:ty; $n:expr $(, $rest:expr)*) => {
+        impl ToIndex for Nat<$n> {
+            type Index = There<$prev>;
+        }
+        impl_to_index!(There<$prev>; $($rest),*);
+    };
+    ($prev:ty;) => {};
+}
+
+impl_to_index!(Here; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+               21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
+               41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60,
+               61, 62, 63);
+
 impl<RHS> Add<RHS> for Nil {
     type Output = RHS;
     
@@ -149,36 +813,662 @@ impl<H, T, RHS> Add<RHS> for Cons<H, T>
     }
 }
 
-#[test]
-fn test_get() {
-    let list = Nil.push(5i32).push("Foo");
-    let a: i32 = *list.get();
-    let b: &str = *list.get();
-    assert!(a == 5i32);
-    assert!(b == "Foo");
+/// Reverses the order of the elements of an `HList`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{HList, Nil, Reverse};
+///
+/// # fn main() {
+/// let list: HList![i32, &str, bool] = Nil.push(true).push("Foo").push(5i32);
+/// let hlist_pat![a, b, c] = list.reverse();
+/// assert!(a);
+/// assert!(b == "Foo");
+/// assert!(c == 5);
+/// # }
+/// ```
+pub trait Reverse {
+    /// The `HList` with its elements in reverse order.
+    type Output;
+
+    /// Consumes the `HList`, and returns it with its elements in reverse order.
+    fn reverse(self) -> Self::Output;
 }
 
-#[test]
-fn test_get_mut() {
-    let mut list = Nil.push(5i32).push("Foo");
-    *list.get_mut() = 6i32;
-    *list.get_mut() = "Bar";
-    let a: i32 = *list.get();
-    let b: &str = *list.get();
-    assert!(a == 6i32);
-    assert!(b == "Bar");
+impl Reverse for Nil {
+    type Output = Nil;
+
+    fn reverse(self) -> Nil {
+        Nil
+    }
 }
 
-#[test]
-fn test_index_as_type_parameter() {
-    fn foo<I, L: Find<i32, I>>(list: &L) -> i32 {
-        *list.get()
+impl<H, T> Reverse for Cons<H, T>
+    where T: Reverse,
+          <T as Reverse>::Output: Add<Cons<H, Nil>> {
+    type Output = <<T as Reverse>::Output as Add<Cons<H, Nil>>>::Output;
+
+    fn reverse(self) -> Self::Output {
+        self.1.reverse() + Cons(self.0, Nil)
     }
-    let list = Nil.push("foo").push(5i32).push("bar");
-    assert!(foo(&list) == 5);
 }
 
-#[test]
+/// A function that can be called with a `T`, for possibly many types `T`.
+///
+/// Implement this for one type for each element type of an `HList`, and wrap a value of it in `Poly`, to `map()` over the `HList`.
+pub trait Func<T> {
+    /// The result of calling the function with a `T`.
+    type Output;
+
+    /// Calls the function.
+    fn call(&mut self, arg: T) -> Self::Output;
+}
+
+/// Wraps a `Func` so that it is applied to every element of an `HList`, rather than treated as an `HList` of functions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Poly<F>(pub F);
+
+/// Applies a function to every element of an `HList`, producing an `HList` of the results.
+///
+/// `F` is either a `Poly` wrapping a `Func` implemented for each element type,
+/// or an `HList` of functions of the same length, with each function applied to the element at its position.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Func, Map, Poly};
+///
+/// struct Describe;
+///
+/// impl Func<i32> for Describe {
+///     type Output = String;
+///     fn call(&mut self, arg: i32) -> String {
+///         format!("int {}", arg)
+///     }
+/// }
+///
+/// impl Func<bool> for Describe {
+///     type Output = String;
+///     fn call(&mut self, arg: bool) -> String {
+///         format!("bool {}", arg)
+///     }
+/// }
+///
+/// # fn main() {
+/// let hlist_pat![a, b] = hlist![5i32, true].map(Poly(Describe));
+/// assert!(a == "int 5");
+/// assert!(b == "bool true");
+///
+/// let hlist_pat![c, d] = hlist![5i32, "Foo"].map(hlist![|x: i32| x + 1, |s: &str| s.len()]);
+/// assert!(c == 6);
+/// assert!(d == 3);
+/// # }
+/// ```
+pub trait Map<F> {
+    /// The `HList` of results.
+    type Output;
+
+    /// Consumes the `HList`, applying `f` to every element.
+    fn map(self, f: F) -> Self::Output;
+}
+
+impl<F> Map<Poly<F>> for Nil {
+    type Output = Nil;
+
+    fn map(self, _: Poly<F>) -> Nil {
+        Nil
+    }
+}
+
+impl Map<Nil> for Nil {
+    type Output = Nil;
+
+    fn map(self, _: Nil) -> Nil {
+        Nil
+    }
+}
+
+impl<F, H, T> Map<Poly<F>> for Cons<H, T>
+    where F: Func<H>,
+          T: Map<Poly<F>> {
+    type Output = Cons<<F as Func<H>>::Output, <T as Map<Poly<F>>>::Output>;
+
+    fn map(self, mut f: Poly<F>) -> Self::Output {
+        let head = f.0.call(self.0);
+        Cons(head, self.1.map(f))
+    }
+}
+
+impl<F, FTail, H, T, R> Map<Cons<F, FTail>> for Cons<H, T>
+    where F: FnOnce(H) -> R,
+          T: Map<FTail> {
+    type Output = Cons<R, <T as Map<FTail>>::Output>;
+
+    fn map(self, f: Cons<F, FTail>) -> Self::Output {
+        Cons((f.0)(self.0), self.1.map(f.1))
+    }
+}
+
+/// Wraps a two-argument `Func`, swapping the order of its arguments.
+///
+/// Used to implement `Foldr` in terms of `Foldl`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Flip<F>(pub F);
+
+impl<F, A, B> Func<(A, B)> for Flip<F>
+    where F: Func<(B, A)> {
+    type Output = <F as Func<(B, A)>>::Output;
+
+    fn call(&mut self, (a, b): (A, B)) -> Self::Output {
+        self.0.call((b, a))
+    }
+}
+
+/// Combines the elements of an `HList` from left to right, starting with `Acc`.
+///
+/// `F` is either a `Poly` wrapping a `Func<(Acc, T)>` implemented for each accumulator and element type,
+/// or an `HList` of functions of the same length, with each function taking the accumulator and the element at its position.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Foldl, Func, Poly};
+///
+/// struct TotalSize;
+///
+/// impl<T> Func<(usize, T)> for TotalSize {
+///     type Output = usize;
+///     fn call(&mut self, (acc, _): (usize, T)) -> usize {
+///         acc + std::mem::size_of::<T>()
+///     }
+/// }
+///
+/// # fn main() {
+/// assert!(hlist![5u8, 6u32].foldl(Poly(TotalSize), 0) == 5);
+///
+/// let described = hlist![5i32, true].foldl(hlist![
+///     |acc: String, x: i32| format!("{}{}", acc, x),
+///     |acc: String, b: bool| format!("{},{}", acc, b),
+/// ], String::new());
+/// assert!(described == "5,true");
+/// # }
+/// ```
+pub trait Foldl<F, Acc> {
+    /// The final accumulated value.
+    type Output;
+
+    /// Consumes the `HList`, folding every element into the accumulator from left to right.
+    fn foldl(self, f: F, acc: Acc) -> Self::Output;
+}
+
+impl<F, Acc> Foldl<Poly<F>, Acc> for Nil {
+    type Output = Acc;
+
+    fn foldl(self, _: Poly<F>, acc: Acc) -> Acc {
+        acc
+    }
+}
+
+impl<Acc> Foldl<Nil, Acc> for Nil {
+    type Output = Acc;
+
+    fn foldl(self, _: Nil, acc: Acc) -> Acc {
+        acc
+    }
+}
+
+impl<F, Acc, H, T> Foldl<Poly<F>, Acc> for Cons<H, T>
+    where F: Func<(Acc, H)>,
+          T: Foldl<Poly<F>, <F as Func<(Acc, H)>>::Output> {
+    type Output = <T as Foldl<Poly<F>, <F as Func<(Acc, H)>>::Output>>::Output;
+
+    fn foldl(self, mut f: Poly<F>, acc: Acc) -> Self::Output {
+        let acc = f.0.call((acc, self.0));
+        self.1.foldl(f, acc)
+    }
+}
+
+impl<F, FTail, Acc, H, T, R> Foldl<Cons<F, FTail>, Acc> for Cons<H, T>
+    where F: FnOnce(Acc, H) -> R,
+          T: Foldl<FTail, R> {
+    type Output = <T as Foldl<FTail, R>>::Output;
+
+    fn foldl(self, f: Cons<F, FTail>, acc: Acc) -> Self::Output {
+        let acc = (f.0)(acc, self.0);
+        self.1.foldl(f.1, acc)
+    }
+}
+
+/// Combines the elements of an `HList` from right to left, starting with `Init`.
+///
+/// `F` is either a `Poly` wrapping a `Func<(T, Acc)>` implemented for each element and accumulator type,
+/// or an `HList` of functions of the same length, with each function taking the element at its position and the accumulator.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Foldr;
+///
+/// # fn main() {
+/// let described = hlist![5i32, true].foldr(hlist![
+///     |x: i32, acc: String| format!("{},{}", acc, x),
+///     |b: bool, acc: String| format!("{}{}", acc, b),
+/// ], String::new());
+/// assert!(described == "true,5");
+/// # }
+/// ```
+pub trait Foldr<F, Init> {
+    /// The final accumulated value.
+    type Output;
+
+    /// Consumes the `HList`, folding every element into the accumulator from right to left.
+    fn foldr(self, f: F, init: Init) -> Self::Output;
+}
+
+impl<L, F, Init> Foldr<Poly<F>, Init> for L
+    where L: Reverse,
+          <L as Reverse>::Output: Foldl<Poly<Flip<F>>, Init> {
+    type Output = <<L as Reverse>::Output as Foldl<Poly<Flip<F>>, Init>>::Output;
+
+    fn foldr(self, f: Poly<F>, init: Init) -> Self::Output {
+        self.reverse().foldl(Poly(Flip(f.0)), init)
+    }
+}
+
+impl<Init> Foldr<Nil, Init> for Nil {
+    type Output = Init;
+
+    fn foldr(self, _: Nil, init: Init) -> Init {
+        init
+    }
+}
+
+impl<F, FTail, Init, H, T, R> Foldr<Cons<F, FTail>, Init> for Cons<H, T>
+    where T: Foldr<FTail, Init>,
+          F: FnOnce(H, <T as Foldr<FTail, Init>>::Output) -> R {
+    type Output = R;
+
+    fn foldr(self, f: Cons<F, FTail>, init: Init) -> R {
+        let acc = self.1.foldr(f.1, init);
+        (f.0)(self.0, acc)
+    }
+}
+
+/// Pairs up the elements of two `HList`s of the same length.
+///
+/// Lists of different lengths do not implement `Zip` for each other.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Unzip, Zip};
+///
+/// # fn main() {
+/// let zipped = hlist![5i32, true].zip(hlist!["Foo", 'c']);
+/// let hlist_pat![a, b] = zipped;
+/// assert!(a == (5, "Foo"));
+/// assert!(b == (true, 'c'));
+///
+/// let (left, right) = zipped.unzip();
+/// assert!(left.0 == 5);
+/// assert!(right.0 == "Foo");
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use] extern crate hlist;
+/// use hlist::Zip;
+///
+/// # fn main() {
+/// let zipped = hlist![5i32, true].zip(hlist!["Foo"]);
+/// # }
+/// ```
+pub trait Zip<Other> {
+    /// The `HList` of pairs.
+    type Output;
+
+    /// Consumes both `HList`s, pairing each element of `self` with the element of `other` at the same position.
+    fn zip(self, other: Other) -> Self::Output;
+}
+
+impl Zip<Nil> for Nil {
+    type Output = Nil;
+
+    fn zip(self, _: Nil) -> Nil {
+        Nil
+    }
+}
+
+impl<H1, T1, H2, T2> Zip<Cons<H2, T2>> for Cons<H1, T1>
+    where T1: Zip<T2> {
+    type Output = Cons<(H1, H2), <T1 as Zip<T2>>::Output>;
+
+    fn zip(self, other: Cons<H2, T2>) -> Self::Output {
+        Cons((self.0, other.0), self.1.zip(other.1))
+    }
+}
+
+/// Splits an `HList` of pairs into two `HList`s, the inverse of `Zip`.
+pub trait Unzip {
+    /// The `HList` of first elements.
+    type Left;
+
+    /// The `HList` of second elements.
+    type Right;
+
+    /// Consumes the `HList`, returning the `HList` of first elements and the `HList` of second elements.
+    fn unzip(self) -> (Self::Left, Self::Right);
+}
+
+impl Unzip for Nil {
+    type Left = Nil;
+    type Right = Nil;
+
+    fn unzip(self) -> (Nil, Nil) {
+        (Nil, Nil)
+    }
+}
+
+impl<A, B, T> Unzip for Cons<(A, B), T>
+    where T: Unzip {
+    type Left = Cons<A, <T as Unzip>::Left>;
+    type Right = Cons<B, <T as Unzip>::Right>;
+
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        let (a, b) = self.0;
+        let (left, right) = self.1.unzip();
+        (Cons(a, left), Cons(b, right))
+    }
+}
+
+/// Turns an `HList` of `Option`s into an `Option` of an `HList`, or an `HList` of `Result`s into a `Result` of an `HList`.
+///
+/// `Out` is either `Option<L>` or `Result<L, E>`, where `L` is the `HList` of the values inside.
+/// Returns `None` (or the first `Err`) if any element is one.
+/// For `Result`, every element must share the error type `E`.
+/// The compiler can infer `Out` from the type of the first element.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::Sequence;
+///
+/// # fn main() {
+/// let hlist_pat![port, host] = hlist![Some(8080u16), Some("localhost")].sequence().unwrap();
+/// assert!(port == 8080);
+/// assert!(host == "localhost");
+///
+/// let parsed = hlist!["5".parse::<i32>(), "x".parse::<i32>()].sequence();
+/// assert!(parsed.is_err());
+/// # }
+/// ```
+pub trait Sequence<Out> {
+    /// Consumes the `HList`, collecting its elements into an `Out`.
+    fn sequence(self) -> Out;
+}
+
+impl Sequence<Option<Nil>> for Nil {
+    fn sequence(self) -> Option<Nil> {
+        Some(Nil)
+    }
+}
+
+impl<E> Sequence<Result<Nil, E>> for Nil {
+    fn sequence(self) -> Result<Nil, E> {
+        Ok(Nil)
+    }
+}
+
+impl<A, T, TailOut> Sequence<Option<Cons<A, TailOut>>> for Cons<Option<A>, T>
+    where T: Sequence<Option<TailOut>> {
+    fn sequence(self) -> Option<Cons<A, TailOut>> {
+        let head = self.0?;
+        Some(Cons(head, self.1.sequence()?))
+    }
+}
+
+impl<A, E, T, TailOut> Sequence<Result<Cons<A, TailOut>, E>> for Cons<Result<A, E>, T>
+    where T: Sequence<Result<TailOut, E>> {
+    fn sequence(self) -> Result<Cons<A, TailOut>, E> {
+        let head = self.0?;
+        Ok(Cons(head, self.1.sequence()?))
+    }
+}
+
+/// Either a value, or every error found while producing it.
+///
+/// Unlike `Result`, which holds the first error, `Validated` holds all of them.
+/// Produced by `Validate`.
+#[derive(Clone, Debug)]
+pub enum Validated<T, E> {
+    /// Every step succeeded.
+    Ok(T),
+    /// At least one step failed. Holds each error, in order.
+    Err(Vec<E>),
+}
+
+impl<T, E> Validated<T, E> {
+    /// Returns `true` if every step succeeded.
+    pub fn is_ok(&self) -> bool {
+        match *self {
+            Validated::Ok(_) => true,
+            Validated::Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if at least one step failed.
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Converts into a `Result` holding either the value or every error.
+    pub fn into_result(self) -> Result<T, Vec<E>> {
+        match self {
+            Validated::Ok(value) => Ok(value),
+            Validated::Err(errors) => Err(errors),
+        }
+    }
+}
+
+/// Turns an `HList` of `Result`s into a `Validated` of an `HList`, keeping every error.
+///
+/// Like `Sequence`, but instead of stopping at the first `Err`, every element is checked and all the errors are collected.
+/// Every element must be a `Result` with the same error type `E`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Validate, Validated};
+///
+/// # fn main() {
+/// let form = hlist!["x".parse::<u16>(), "30".parse::<u32>(), "y".parse::<u8>()];
+/// match form.validate() {
+///     Validated::Ok(_) => panic!("should fail"),
+///     Validated::Err(errors) => assert!(errors.len() == 2),
+/// }
+///
+/// let form = hlist!["8080".parse::<u16>(), "30".parse::<u32>()];
+/// let hlist_pat![port, timeout] = form.validate().into_result().unwrap();
+/// assert!(port == 8080);
+/// assert!(timeout == 30);
+/// # }
+/// ```
+pub trait Validate<Out, E>: Sized {
+    /// Consumes the `HList`, collecting its values into a `Validated`.
+    fn validate(self) -> Validated<Out, E> {
+        let mut errors = Vec::new();
+        match self.validate_into(&mut errors) {
+            Some(values) => Validated::Ok(values),
+            None => Validated::Err(errors),
+        }
+    }
+
+    /// Consumes the `HList`, appending every error to `errors` in order.
+    /// Returns the values if there were no errors.
+    ///
+    /// Used to implement `validate()`.
+    fn validate_into(self, errors: &mut Vec<E>) -> Option<Out>;
+}
+
+impl<E> Validate<Nil, E> for Nil {
+    fn validate_into(self, _: &mut Vec<E>) -> Option<Nil> {
+        Some(Nil)
+    }
+}
+
+impl<A, E, T, TailOut> Validate<Cons<A, TailOut>, E> for Cons<Result<A, E>, T>
+    where T: Validate<TailOut, E> {
+    fn validate_into(self, errors: &mut Vec<E>) -> Option<Cons<A, TailOut>> {
+        let head = self.0.map_err(|error| errors.push(error));
+        let tail = self.1.validate_into(errors);
+        Some(Cons(head.ok()?, tail?))
+    }
+}
+
+/// Converts an `HList` into the tuple with the same elements, in order.
+///
+/// Implemented for `HList`s of up to 32 elements.
+/// The reverse conversion is available through `From`.
+///
+/// ```rust
+/// #[macro_use] extern crate hlist;
+/// use hlist::IntoTuple;
+///
+/// # fn main() {
+/// let list: HList![i32, &str, bool] = (5i32, "Foo", true).into();
+/// assert!(list.into_tuple() == (5, "Foo", true));
+/// # }
+/// ```
+pub trait IntoTuple {
+    /// The tuple type with the same elements as the `HList`.
+    type Tuple;
+
+    /// Consumes the `HList`, returning its elements as a tuple.
+    fn into_tuple(self) -> Self::Tuple;
+}
+
+impl From<()> for Nil {
+    fn from(_: ()) -> Nil {
+        Nil
+    }
+}
+
+impl IntoTuple for Nil {
+    type Tuple = ();
+
+    fn into_tuple(self) {}
+}
+
+macro_rules! impl_tuple_conversions {
+    () => {};
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first $(, $rest)*> From<($first, $($rest,)*)> for HList![$first $(, $rest)*] {
+            #[allow(non_snake_case)]
+            fn from(($first, $($rest,)*): ($first, $($rest,)*)) -> Self {
+                hlist![$first $(, $rest)*]
+            }
+        }
+
+        impl<$first $(, $rest)*> IntoTuple for HList![$first $(, $rest)*] {
+            type Tuple = ($first, $($rest,)*);
+
+            #[allow(non_snake_case)]
+            fn into_tuple(self) -> Self::Tuple {
+                let hlist_pat![$first $(, $rest)*] = self;
+                ($first, $($rest,)*)
+            }
+        }
+
+        impl_tuple_conversions!($($rest),*);
+    };
+}
+
+impl_tuple_conversions!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18, T19, T20, T21, T22, T23, T24, T25, T26, T27, T28, T29, T30, T31, T32);
+
+/// Converts a type to and from an `HList` with the same contents, its generic representation.
+///
+/// With the `derive` feature, `#[derive(Generic)]` implements this for structs and tuple structs,
+/// using an `HList` of the fields in declaration order.
+/// This lets `Find` and the other `HList` operations be used on ordinary structs.
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+/// #[macro_use] extern crate hlist;
+/// use hlist::{Find, Generic};
+///
+/// #[derive(Generic)]
+/// struct Event {
+///     name: &'static str,
+///     timestamp: u64,
+/// }
+///
+/// fn timestamp<T: Generic<Repr = R>, R: Find<u64, I>, I>(value: T) -> u64 {
+///     *value.into_hlist().get()
+/// }
+///
+/// # fn main() {
+/// assert!(timestamp(Event { name: "start", timestamp: 5 }) == 5);
+///
+/// let event = Event::from_hlist(hlist!["stop", 6]);
+/// assert!(event.name == "stop");
+/// # }
+/// ```
+pub trait Generic: Sized {
+    /// The `HList` representation of the type.
+    type Repr;
+
+    /// Converts the value into its `HList` representation.
+    fn into_hlist(self) -> Self::Repr;
+
+    /// Builds a value from its `HList` representation.
+    fn from_hlist(repr: Self::Repr) -> Self;
+}
+
+mod labelled;
+
+pub use labelled::{Field, FindField, Identity, Label, LabelledGeneric, Nested, PluckField, Transmogrify, TransmogrifyRecord};
+#[doc(hidden)]
+pub use labelled::label_hash;
+
+mod coproduct;
+
+pub use coproduct::{CNil, Coproduct, Embed, Fold, Inject, Subset, Uninject};
+
+mod container;
+
+pub use container::{Construct, Container, Existing, FindProvider, Provided, Provider, Resolve, ResolveMany};
+
+mod hset;
+
+pub use hset::{ContainsAll, Difference, Distinct, HSet, Intersect, Union};
+
+#[test]
+#[allow(clippy::explicit_auto_deref)]
+fn test_get() {
+    let list = Nil.push(5i32).push("Foo");
+    let a: i32 = *list.get();
+    let b: &str = *list.get();
+    assert!(a == 5i32);
+    assert!(b == "Foo");
+}
+
+#[test]
+#[allow(clippy::explicit_auto_deref)]
+fn test_get_mut() {
+    let mut list = Nil.push(5i32).push("Foo");
+    *list.get_mut() = 6i32;
+    *list.get_mut() = "Bar";
+    let a: i32 = *list.get();
+    let b: &str = *list.get();
+    assert!(a == 6i32);
+    assert!(b == "Bar");
+}
+
+#[test]
+fn test_index_as_type_parameter() {
+    fn foo<I, L: Find<i32, I>>(list: &L) -> i32 {
+        *list.get()
+    }
+    let list = Nil.push("foo").push(5i32).push("bar");
+    assert!(foo(&list) == 5);
+}
+
+#[test]
+#[allow(clippy::explicit_auto_deref)]
 fn test_hlist_addition() {
     let list_1 = Nil.push(0i32);
     let list_2 = Nil.push("Foo");
@@ -188,3 +1478,375 @@ fn test_hlist_addition() {
     assert!(a == 0i32);
     assert!(b == "Foo");
 }
+
+#[test]
+fn test_hlist_macro_empty() {
+    let list: HList![] = hlist![];
+    let Nil = list;
+}
+
+#[test]
+fn test_hlist_macro_single() {
+    let list: HList![i32,] = hlist![5i32,];
+    assert!(list.0 == 5i32);
+}
+
+#[test]
+fn test_hlist_macro_order() {
+    let list: HList![i32, &str, bool] = hlist![5i32, "Foo", true];
+    let Cons(a, Cons(b, Cons(c, Nil))) = list;
+    assert!(a == 5i32);
+    assert!(b == "Foo");
+    assert!(c);
+}
+
+#[test]
+fn test_hlist_macro_long() {
+    let list: HList![u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, bool, char,
+                     &str, (), usize, isize, u128, i128, String, Option<u8>, Vec<u8>] =
+        hlist![1u8, 2u16, 3u32, 4u64, 5i8, 6i16, 7i32, 8i64, 9f32, 10f64, true, 'c',
+               "s", (), 15usize, 16isize, 17u128, 18i128, String::from("x"), Some(20u8), vec![21u8]];
+    let a: u8 = *list.get();
+    let b: i128 = *list.get();
+    let c: &Vec<u8> = list.get();
+    assert!(a == 1);
+    assert!(b == 18);
+    assert!(c == &vec![21u8]);
+}
+
+#[test]
+fn test_hlist_pat() {
+    let hlist_pat![a, b, c] = hlist![5i32, "Foo", true];
+    assert!(a == 5i32);
+    assert!(b == "Foo");
+    assert!(c);
+}
+
+#[test]
+fn test_hlist_pat_rest() {
+    let hlist_pat![a, _, ..rest] = hlist![5i32, "Foo", true, 'c'];
+    assert!(a == 5i32);
+    let hlist_pat![b, c,] = rest;
+    assert!(b);
+    assert!(c == 'c');
+}
+
+#[test]
+fn test_hlist_pat_match() {
+    let list = hlist![Some(5i32), "Foo"];
+    match list {
+        hlist_pat![None, ..] => panic!("expected Some"),
+        hlist_pat![Some(a), ..] => assert!(a == 5i32),
+    }
+}
+
+#[test]
+fn test_pluck() {
+    let list = hlist![5i32, String::from("Foo"), true];
+    let (a, rest): (String, _) = list.pluck();
+    let hlist_pat![b, c] = rest;
+    assert!(a == "Foo");
+    assert!(b == 5i32);
+    assert!(c);
+}
+
+#[test]
+fn test_pluck_as_type_parameter() {
+    fn take_string<I, L: Pluck<String, I>>(list: L) -> (String, L::Remainder) {
+        list.pluck()
+    }
+    let (a, rest) = take_string(hlist![5i32, String::from("Foo")]);
+    let hlist_pat![b] = rest;
+    assert!(a == "Foo");
+    assert!(b == 5i32);
+}
+
+#[test]
+fn test_replace() {
+    let list = hlist![5i32, "Foo"];
+    let (old, list) = list.replace("Bar");
+    let b: &str = (list.1).0;
+    assert!(old == "Foo");
+    assert!(b == "Bar");
+    assert!(list.0 == 5i32);
+}
+
+#[test]
+fn test_replace_with() {
+    struct Raw(&'static str);
+    struct Parsed(i32);
+    let list = hlist![true, Raw("5"), 'c'];
+    let list = list.replace_with(|raw: Raw| Parsed(raw.0.parse().unwrap()));
+    let hlist_pat![a, Parsed(b), c] = list;
+    assert!(a);
+    assert!(b == 5);
+    assert!(c == 'c');
+}
+
+#[test]
+#[allow(clippy::type_complexity)]
+fn test_sculpt_permutation() {
+    let list = hlist![5i32, "Foo", true];
+    let (target, Nil): (HList![bool, &str, i32], _) = list.sculpt();
+    let hlist_pat![a, b, c] = target;
+    assert!(a);
+    assert!(b == "Foo");
+    assert!(c == 5i32);
+}
+
+#[test]
+fn test_sculpt_subset() {
+    let list = hlist![5i32, "Foo", true, 'c'];
+    let (target, rest): (HList![char, i32], _) = list.sculpt();
+    let hlist_pat![a, b] = target;
+    let hlist_pat![c, d] = rest;
+    assert!(a == 'c');
+    assert!(b == 5i32);
+    assert!(c == "Foo");
+    assert!(d);
+}
+
+#[test]
+fn test_get_many() {
+    let list = hlist![5i32, "Foo", true];
+    let hlist_pat![a, b, c] = list.get_many::<HList![&str, i32, i32], _>();
+    assert!(*a == "Foo");
+    assert!(*b == 5i32);
+    assert!(*c == 5i32);
+}
+
+#[test]
+fn test_get_many_mut() {
+    struct Position(i32);
+    struct Velocity(i32);
+    let mut list = hlist![Position(0), "Foo", Velocity(2)];
+    {
+        let hlist_pat![velocity, position] = list.get_many_mut::<HList![Velocity, Position], _>();
+        position.0 += velocity.0;
+        velocity.0 = 0;
+    }
+    let hlist_pat![Position(p), _, Velocity(v)] = list;
+    assert!(p == 2);
+    assert!(v == 0);
+}
+
+#[test]
+#[allow(clippy::explicit_auto_deref)]
+fn test_to_ref() {
+    let list = hlist![5i32, String::from("Foo")];
+    let view = list.to_ref();
+    let a: &i32 = *view.get();
+    let b: &String = *view.get();
+    assert!(*a == 5i32);
+    assert!(b == "Foo");
+    let (c, _): (&String, _) = list.to_ref().pluck();
+    assert!(c == "Foo");
+}
+
+#[test]
+fn test_to_mut() {
+    let mut list = hlist![5i32, String::from("Foo")];
+    {
+        let view = list.to_mut();
+        let (a, rest): (&mut i32, _) = view.pluck();
+        let hlist_pat![b] = rest;
+        *a += 1;
+        b.push_str("Bar");
+    }
+    let hlist_pat![a, b] = list;
+    assert!(a == 6i32);
+    assert!(b == "FooBar");
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn test_len() {
+    let list = hlist![5i32, "Foo", true];
+    assert!(list.len() == 3);
+    assert!(!list.is_empty());
+    assert!(Nil.is_empty());
+    assert!(<HList![i32, &str]>::LEN == 2);
+}
+
+#[test]
+fn test_type_level_len() {
+    fn zip_len<A: HList, B: HList<Len = A::Len>>(a: &A, b: &B) -> usize {
+        a.len() + b.len()
+    }
+    assert!(zip_len(&hlist![5i32, "Foo"], &hlist![true, 'c']) == 4);
+    assert!(zip_len(&Nil, &Nil) == 0);
+}
+
+#[test]
+fn test_at() {
+    let mut list = hlist![1u32, "Foo", 2u32];
+    *list.at_mut::<0>() += 10;
+    assert!(*list.at::<0>() == 11u32);
+    assert!(*list.at::<1>() == "Foo");
+    assert!(*list.at::<2>() == 2u32);
+}
+
+#[test]
+fn test_reverse() {
+    let list: HList![bool, &str, i32] = hlist![5i32, "Foo", true].reverse();
+    let hlist_pat![a, b, c] = list;
+    assert!(a);
+    assert!(b == "Foo");
+    assert!(c == 5i32);
+    let Nil = Nil.reverse();
+}
+
+#[test]
+fn test_map_poly() {
+    struct Validate;
+    impl Func<&'static str> for Validate {
+        type Output = Result<String, ()>;
+        fn call(&mut self, arg: &'static str) -> Result<String, ()> {
+            if arg.is_empty() { Err(()) } else { Ok(arg.to_string()) }
+        }
+    }
+    impl Func<i32> for Validate {
+        type Output = Result<u32, ()>;
+        fn call(&mut self, arg: i32) -> Result<u32, ()> {
+            if arg < 0 { Err(()) } else { Ok(arg as u32) }
+        }
+    }
+    let hlist_pat![a, b, c] = hlist!["Foo", 5i32, -1i32].map(Poly(Validate));
+    assert!(a == Ok(String::from("Foo")));
+    assert!(b == Ok(5u32));
+    assert!(c == Err(()));
+}
+
+#[test]
+fn test_map_poly_state() {
+    struct Count(usize);
+    impl<T> Func<T> for Count {
+        type Output = usize;
+        fn call(&mut self, _: T) -> usize {
+            self.0 += 1;
+            self.0
+        }
+    }
+    let hlist_pat![a, b, c] = hlist![5i32, "Foo", true].map(Poly(Count(0)));
+    assert!(a == 1 && b == 2 && c == 3);
+}
+
+#[test]
+fn test_map_closures() {
+    let suffix = String::from("Bar");
+    let list = hlist![5i32, String::from("Foo")];
+    let hlist_pat![a, b] = list.map(hlist![|x: i32| x * 2, move |s: String| s + &suffix]);
+    assert!(a == 10i32);
+    assert!(b == "FooBar");
+}
+
+#[test]
+fn test_foldl_poly() {
+    struct Join;
+    impl<T: std::fmt::Debug> Func<(String, T)> for Join {
+        type Output = String;
+        fn call(&mut self, (acc, x): (String, T)) -> String {
+            format!("{}{:?};", acc, x)
+        }
+    }
+    let joined = hlist![5i32, "Foo", true].foldl(Poly(Join), String::new());
+    assert!(joined == "5;\"Foo\";true;");
+}
+
+#[test]
+fn test_foldr_poly() {
+    struct Join;
+    impl<T: std::fmt::Debug> Func<(T, String)> for Join {
+        type Output = String;
+        fn call(&mut self, (x, acc): (T, String)) -> String {
+            format!("{}{:?};", acc, x)
+        }
+    }
+    let joined = hlist![5i32, "Foo", true].foldr(Poly(Join), String::new());
+    assert!(joined == "true;\"Foo\";5;");
+}
+
+#[test]
+fn test_fold_closures() {
+    let sum = hlist![5i32, 2u8].foldl(hlist![|acc: i64, x: i32| acc + x as i64, |acc: i64, x: u8| acc * x as i64], 1i64);
+    assert!(sum == 12i64);
+    let sum = hlist![5i32, 2u8].foldr(hlist![|x: i32, acc: i64| acc + x as i64, |x: u8, acc: i64| acc * x as i64], 1i64);
+    assert!(sum == 7i64);
+}
+
+#[test]
+fn test_call() {
+    let list = hlist![1u8, 2u16, 3u32, 4u64, 5i8, 6i16, 7i32, 8i64, 9usize, 10isize, 11f32, 12f64, "Foo"];
+    assert!(list.call(|| 0) == 0);
+    assert!(list.call(|a: &i32, s: &&str| *a as usize + s.len()) == 10);
+
+    #[allow(clippy::too_many_arguments)]
+    fn sum(a: &f64, b: &f32, c: &isize, d: &usize, e: &i64, f: &i32, g: &i16, h: &i8, i: &u64, j: &u32, k: &u16, l: &u8) -> f64 {
+        a + *b as f64 + (*c as f64) + (*d as f64) + (*e as f64) + (*f as f64)
+            + (*g as f64) + (*h as f64) + (*i as f64) + (*j as f64) + (*k as f64) + (*l as f64)
+    }
+    assert!(list.call(sum) == 78.0);
+}
+
+#[test]
+fn test_zip_unzip() {
+    let handlers = hlist![|x: i32| x + 1, |s: &str| s.len()];
+    let configs = hlist![5i32, "Foo"];
+    let hlist_pat![(f, x), (g, s)] = handlers.zip(configs);
+    assert!(f(x) == 6);
+    assert!(g(s) == 3);
+
+    let (left, right) = hlist![(5i32, "Foo"), (true, 'c')].unzip();
+    let hlist_pat![a, b] = left;
+    let hlist_pat![c, d] = right;
+    assert!(a == 5i32 && b);
+    assert!(c == "Foo" && d == 'c');
+}
+
+#[test]
+fn test_sequence() {
+    let some: Option<HList![i32, &str]> = hlist![Some(5i32), Some("Foo")].sequence();
+    assert!(some.map(|list| list.0) == Some(5));
+    let none: Option<HList![i32, &str]> = hlist![Some(5i32), None].sequence();
+    assert!(none.is_none());
+
+    let ok: Result<HList![i32, bool], &str> = hlist![Ok(5i32), Ok(true)].sequence();
+    assert!(ok.map(|list| (list.1).0) == Ok(true));
+    let err: Result<HList![i32, bool], &str> = hlist![Err("first"), Err("second")].sequence();
+    assert!(err.err() == Some("first"));
+}
+
+#[test]
+#[allow(clippy::type_complexity)]
+fn test_validate() {
+    let ok: Validated<HList![i32, bool], &str> = hlist![Ok(5i32), Ok(true)].validate();
+    assert!(ok.is_ok());
+
+    let err: Validated<HList![i32, bool, char], &str> = hlist![Err("first"), Ok(true), Err("third")].validate();
+    assert!(err.into_result().err() == Some(vec!["first", "third"]));
+}
+
+#[test]
+fn test_tuple_conversions() {
+    let list: HList![] = ().into();
+    let () = list.into_tuple();
+
+    let list: HList![i32] = (5i32,).into();
+    assert!(list.into_tuple() == (5i32,));
+
+    let list = Cons::from((5i32, "Foo", true));
+    let hlist_pat![a, b, c] = list;
+    assert!(a == 5i32 && b == "Foo" && c);
+    assert!(list.into_tuple() == (5i32, "Foo", true));
+}
+
+#[test]
+fn test_tuple_conversions_long() {
+    let tuple = (1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8,
+                 17u8, 18u8, 19u8, 20u8, 21u8, 22u8, 23u8, 24u8, 25u8, 26u8, 27u8, 28u8, 29u8, 30u8, 31u8, 32u8);
+    let list = Cons::from(tuple);
+    assert!(list.len() == 32);
+    assert!(*list.at::<31>() == 32u8);
+    let (a, .., b) = list.into_tuple();
+    assert!(a == 1u8 && b == 32u8);
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "derive")]
+
+#[macro_use]
+extern crate hlist;
+
+use hlist::{Find, Generic, Identity, LabelledGeneric, Nested, Transmogrify};
+
+#[derive(Generic, Debug, PartialEq)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Pair<A, B>(A, B);
+
+#[derive(Generic, Debug, PartialEq)]
+struct Unit;
+
+#[test]
+fn test_named_struct() {
+    let person = Person { name: String::from("Foo"), age: 30 };
+    let repr: HList![String, u32] = person.into_hlist();
+    let hlist_pat![name, age] = repr;
+    assert!(name == "Foo");
+    assert!(age == 30);
+    let person = Person::from_hlist(hlist![String::from("Bar"), 40]);
+    assert!(person == Person { name: String::from("Bar"), age: 40 });
+}
+
+#[test]
+fn test_tuple_struct() {
+    let pair = Pair(5i32, "Foo");
+    let repr: HList![i32, &str] = pair.into_hlist();
+    assert!(Pair::from_hlist(repr) == Pair(5i32, "Foo"));
+}
+
+#[test]
+fn test_unit_struct() {
+    let hlist_pat![] = Unit.into_hlist();
+    assert!(Unit::from_hlist(hlist![]) == Unit);
+}
+
+#[test]
+fn test_find_on_struct() {
+    fn age<T: Generic<Repr = R>, R: Find<u32, I>, I>(value: T) -> u32 {
+        *value.into_hlist().get()
+    }
+    assert!(age(Person { name: String::from("Foo"), age: 30 }) == 30);
+}
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+struct NameDto {
+    last_name: String,
+    first_name: String,
+    middle_name: String,
+}
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+struct Name {
+    first_name: String,
+    last_name: String,
+}
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+struct UserDto {
+    id: u64,
+    name: NameDto,
+    email: String,
+}
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+struct User {
+    email: String,
+    name: Name,
+}
+
+#[test]
+fn test_labelled_generic() {
+    use hlist::HList;
+
+    let name = Name { first_name: String::from("Foo"), last_name: String::from("Bar") };
+    let repr = name.into_labelled();
+    assert!(repr.get_field::<Label![first_name], _>() == "Foo");
+    assert!(repr.get_field::<Label![last_name], _>() == "Bar");
+    assert!(repr.0.name == "first_name");
+    let name = Name::from_labelled(record!{ first_name: String::from("Baz"), last_name: String::from("Qux") });
+    assert!(name == Name { first_name: String::from("Baz"), last_name: String::from("Qux") });
+}
+
+#[test]
+fn test_transmogrify_reorder_and_subset() {
+    let dto = NameDto { last_name: String::from("Bar"), first_name: String::from("Foo"), middle_name: String::from("Baz") };
+    let name: Name = dto.transmogrify();
+    assert!(name == Name { first_name: String::from("Foo"), last_name: String::from("Bar") });
+}
+
+#[test]
+fn test_transmogrify_nested() {
+    let dto = UserDto {
+        id: 5,
+        name: NameDto { last_name: String::from("Bar"), first_name: String::from("Foo"), middle_name: String::from("Baz") },
+        email: String::from("foo@example.com"),
+    };
+    let user: User = dto.transmogrify();
+    assert!(user == User {
+        email: String::from("foo@example.com"),
+        name: Name { first_name: String::from("Foo"), last_name: String::from("Bar") },
+    });
+}
+
+#[test]
+fn test_transmogrify_reorder_exact() {
+    let name = Name { first_name: String::from("Foo"), last_name: String::from("Bar") };
+    let dto: NameDtoReversed = name.transmogrify();
+    assert!(dto == NameDtoReversed { last_name: String::from("Bar"), first_name: String::from("Foo") });
+}
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+struct NameDtoReversed {
+    last_name: String,
+    first_name: String,
+}
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+struct AccountDto {
+    id: u64,
+    name: Name,
+    email: String,
+}
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+struct Account {
+    email: String,
+    name: Name,
+}
+
+#[test]
+fn test_transmogrify_shared_nested_type() {
+    let dto = AccountDto {
+        id: 5,
+        name: Name { first_name: String::from("Foo"), last_name: String::from("Bar") },
+        email: String::from("foo@example.com"),
+    };
+    // `name` has the same type in both, so its value index is named as `Identity`.
+    let account = Transmogrify::<Account, Nested<HList![(_, _), (_, Identity)]>>::transmogrify(dto);
+    assert!(account == Account {
+        email: String::from("foo@example.com"),
+        name: Name { first_name: String::from("Foo"), last_name: String::from("Bar") },
+    });
+}